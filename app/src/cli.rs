@@ -42,7 +42,14 @@ pub struct Cli {
     #[command(flatten, next_display_order = 3000)]
     pub config: KubeConfigOptions,
 
-    #[arg(short, long, value_enum, global = true, display_order = 10000)]
+    /// Output format. One of: (json, yaml, name, go-template, go-template-file, template, templatefile, jsonpath, jsonpath-as-json, jsonpath-file, custom-columns, custom-columns-file, wide, csv, tsv, markdown)
+    #[arg(
+        short,
+        long,
+        value_name = "FORMAT",
+        global = true,
+        display_order = 10000
+    )]
     pub output: Option<OutputFormat>,
 
     #[command(subcommand)]
@@ -60,13 +67,21 @@ impl Cli {
     }
 
     pub async fn exec(self) -> RkResult<()> {
-        let kubeapi = self.kubeapi().await?;
+        let kubeapi = if self.command.is_offline() {
+            Kubeapi::offline(&self.config, self.debug)?.with_namespace(self.namespace.namespace())
+        } else {
+            self.kubeapi().await?
+        };
+        let impersonation = kubeapi.impersonation();
         let ui = self.ui();
         let context = Context::new(kubeapi, ui);
-        self.command.exec(&context).await
+        self.command
+            .exec(&context)
+            .await
+            .map_err(|err| err.with_impersonation(impersonation))
     }
 
-    async fn kubeapi(&self) -> kube::Result<Kubeapi> {
+    async fn kubeapi(&self) -> RkResult<Kubeapi> {
         let namespace = self.namespace.namespace();
         let kubeapi = Kubeapi::new(&self.config, &self.options, self.debug)
             .await?
@@ -81,7 +96,7 @@ impl Cli {
     }
 
     fn ui(&self) -> Ui {
-        let output = self.output.unwrap_or_default();
+        let output = self.output.clone().unwrap_or_default();
         Ui::new(output)
     }
 }
@@ -104,6 +104,11 @@ impl Command {
         }
     }
 
+    /// Commands that work on kubeconfig alone and must not need a reachable cluster
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Config(_))
+    }
+
     async fn api_versions(&self, context: &Context) -> RkResult<()> {
         let ui = context.ui();
         context
@@ -120,7 +125,7 @@ impl Command {
         let show_params: ShowParams = default();
         context
             .ui()
-            .print(features.output(false, &show_params, output));
+            .print(features.output(false, &show_params, output)?);
         Ok(())
     }
 
@@ -74,9 +74,9 @@ impl ClusterInfo {
             .into_iter()
             .map(|svc| ClusterService::from_svc(&kubeapi, svc));
 
-        iter::once(ClusterService::control_plane(&kubeapi))
-            .chain(services)
-            .for_each(|svc| context.ui().show(svc, &default()));
+        for svc in iter::once(ClusterService::control_plane(&kubeapi)).chain(services) {
+            context.ui().show(svc, &default())?;
+        }
 
         Ok(())
     }
@@ -143,23 +143,22 @@ impl ClusterService {
     }
 }
 
+impl serde::Serialize for ClusterService {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.svc.serialize(serializer)
+    }
+}
+
 impl Show for ClusterService {
-    fn output(&self, _namespace: bool, params: &ShowParams, output: &OutputFormat) -> String {
-        match output {
-            OutputFormat::Normal => format!("{} is running on {}", self.name, self.url),
-            OutputFormat::Json => self.json(params),
-            OutputFormat::Yaml => self.yaml(params),
-            OutputFormat::Name => self.name(),
-            OutputFormat::GoTemplate => todo!(),
-            OutputFormat::GoTemplateFile => todo!(),
-            OutputFormat::Template => todo!(),
-            OutputFormat::TemplateFile => todo!(),
-            OutputFormat::JsonPath => todo!(),
-            OutputFormat::JsonPathAsJson => todo!(),
-            OutputFormat::JsonPathFile => todo!(),
-            OutputFormat::CustomColumns => todo!(),
-            OutputFormat::CustomColumnsFile => todo!(),
-            OutputFormat::Wide => todo!(),
+    fn table(
+        &self,
+        namespace: bool,
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> Result<String, ShowError> {
+        match (&self.svc, output) {
+            (Some(svc), OutputFormat::Wide) => svc.table(namespace, params, output),
+            _ => Ok(format!("{} is running on {}", self.name, self.url)),
         }
     }
 
@@ -177,21 +176,7 @@ impl Show for ClusterService {
             .unwrap_or_default()
     }
 
-    fn json(&self, params: &ShowParams) -> String {
-        self.svc
-            .as_ref()
-            .map(|svc| svc.json(params))
-            .unwrap_or_default()
-    }
-
-    fn yaml(&self, params: &ShowParams) -> String {
-        self.svc
-            .as_ref()
-            .map(|svc| svc.yaml(params))
-            .unwrap_or_default()
-    }
-
-    fn name(&self) -> String {
-        self.name.clone()
+    fn name(&self) -> Result<String, ShowError> {
+        Ok(self.name.clone())
     }
 }
@@ -1,5 +1,23 @@
+use rkubectl_kubeapi::Stanza;
+
+use ext::Base64Encode;
+
 use super::*;
 
+use cluster::DeleteCluster;
+use cluster::SetCluster;
+use context::DeleteContext;
+use context::GetContexts;
+use context::RenameContext;
+use context::SetContext;
+use context::UseContext;
+use user::DeleteUser;
+use user::SetCredentials;
+
+mod cluster;
+mod context;
+mod user;
+
 /// Modify kubeconfig files using subcommands like "kubectl config set current-context my-context".
 ///  The loading order follows these rules:
 ///   1.  If the --kubeconfig flag is set, then only that file is loaded.
@@ -16,31 +34,31 @@ pub enum Config {
     /// Display the current-context
     CurrentContext,
     /// Delete the specified cluster from the kubeconfig
-    DeleteCluster,
+    DeleteCluster(DeleteCluster),
     /// Delete the specified context from the kubeconfig
-    DeleteContext,
+    DeleteContext(DeleteContext),
     /// Delete the specified user from the kubeconfig
-    DeleteUser,
+    DeleteUser(DeleteUser),
     /// Display clusters defined in the kubeconfig
     GetClusters,
     /// Describe one or many contexts
-    GetContexts,
+    GetContexts(GetContexts),
     /// Display users defined in the kubeconfig
     GetUsers,
     /// Rename a context from the kubeconfig file
-    RenameContext,
+    RenameContext(RenameContext),
     /// Set an individual value in a kubeconfig file
     Set,
     /// Set a cluster entry in kubeconfig
-    SetCluster,
+    SetCluster(SetCluster),
     /// Set a context entry in kubeconfig
-    SetContext,
+    SetContext(SetContext),
     /// Set a user entry in kubeconfig
-    SetCredentials,
+    SetCredentials(Box<SetCredentials>),
     /// Unset an individual value in a kubeconfig file
     Unset,
     /// Set the current-context in a kubeconfig file
-    UseContext,
+    UseContext(UseContext),
     /// Display merged kubeconfig settings or a specified kubeconfig file
     View,
 }
@@ -55,34 +73,29 @@ impl Config {
                     ui.print(ctx);
                 }
             }
-            Self::DeleteCluster => Err(RkError::todo())?,
-            Self::DeleteContext => Err(RkError::todo())?,
-            Self::DeleteUser => Err(RkError::todo())?,
+            Self::DeleteCluster(delete_cluster) => delete_cluster.exec(context)?,
+            Self::DeleteContext(delete_context) => delete_context.exec(context)?,
+            Self::DeleteUser(delete_user) => delete_user.exec(context)?,
             Self::GetClusters => {
                 kubeapi
                     .get_clusters()
                     .iter()
                     .for_each(|cluster| ui.print(&cluster.name));
             }
-            Self::GetContexts => {
-                kubeapi
-                    .get_contexts()
-                    .iter()
-                    .for_each(|ctx| ui.print(&ctx.name));
-            }
+            Self::GetContexts(get_contexts) => get_contexts.exec(context)?,
             Self::GetUsers => {
                 kubeapi
                     .get_users()
                     .iter()
                     .for_each(|auth| ui.print(&auth.name));
             }
-            Self::RenameContext => Err(RkError::todo())?,
+            Self::RenameContext(rename_context) => rename_context.exec(context)?,
             Self::Set => Err(RkError::todo())?,
-            Self::SetCluster => Err(RkError::todo())?,
-            Self::SetContext => Err(RkError::todo())?,
-            Self::SetCredentials => Err(RkError::todo())?,
+            Self::SetCluster(set_cluster) => set_cluster.exec(context)?,
+            Self::SetContext(set_context) => set_context.exec(context)?,
+            Self::SetCredentials(set_credentials) => set_credentials.exec(context)?,
             Self::Unset => Err(RkError::todo())?,
-            Self::UseContext => Err(RkError::todo())?,
+            Self::UseContext(use_context) => use_context.exec(context)?,
             Self::View => {
                 if let Ok(text) = kubeapi.view() {
                     ui.print(text);
@@ -92,3 +105,41 @@ impl Config {
         Ok(())
     }
 }
+
+/// Remove the stanza from every kubeconfig file that defines it
+fn delete<S: Stanza>(context: &Context, kind: &'static str, name: &str) -> RkResult<()> {
+    let mut kubeconfig = context.kubeapi().kubeconfig_files().clone();
+    let origin = kubeconfig
+        .origin::<S>(name)
+        .map(|path| path.display().to_string())
+        .ok_or_else(|| not_found(kind, name))?;
+    kubeconfig.remove::<S>(name)?;
+    kubeconfig.save()?;
+    context
+        .ui()
+        .print(format!("deleted {kind} {name} from {origin}"));
+    Ok(())
+}
+
+fn not_found(kind: &'static str, name: &str) -> RkError {
+    RkError::NotInKubeconfig {
+        kind,
+        name: name.to_string(),
+    }
+}
+
+/// File content the way the `*-data` fields hold it
+fn embed(path: &Path) -> RkResult<String> {
+    let data = fs::read(path).map_err(|source| RkError::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let encoded = data.as_slice().encode();
+    Ok(String::from_utf8_lossy(&encoded.0).into_owned())
+}
+
+/// Relative paths in kubeconfig are relative to the kubeconfig file, not to where we run
+fn absolute(path: &Path) -> RkResult<String> {
+    let path = std::path::absolute(path)?;
+    Ok(path.display().to_string())
+}
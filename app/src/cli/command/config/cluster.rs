@@ -0,0 +1,85 @@
+use kube::config::Cluster;
+use kube::config::NamedCluster;
+
+use super::*;
+
+const KIND: &str = "cluster";
+
+/// The server, certificate authority, TLS server name and TLS verification come from
+/// the global connection flags, which would otherwise clash with options of the same name
+#[derive(Clone, Debug, Args)]
+pub struct SetCluster {
+    /// Cluster to create or modify
+    name: String,
+
+    /// Store the certificate authority data in kubeconfig instead of the path to it
+    #[arg(long)]
+    embed_certs: bool,
+
+    /// URL of the proxy to reach the server through
+    #[arg(long)]
+    proxy_url: Option<String>,
+}
+
+impl SetCluster {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let mut kubeconfig = kubeapi.kubeconfig_files().clone();
+        let mut named = kubeconfig
+            .get::<NamedCluster>(&self.name)
+            .unwrap_or_else(|| NamedCluster {
+                name: self.name.clone(),
+                cluster: None,
+            });
+        self.apply(
+            kubeapi.kubeconfig_options(),
+            named.cluster.get_or_insert_default(),
+        )?;
+        kubeconfig.upsert(named)?;
+        kubeconfig.save()?;
+        context
+            .ui()
+            .print(format!("Cluster \"{}\" set.", self.name));
+        Ok(())
+    }
+
+    fn apply(&self, options: &KubeConfigOptions, cluster: &mut Cluster) -> RkResult<()> {
+        if let Some(server) = &options.server {
+            cluster.server = Some(server.clone());
+        }
+        if let Some(path) = &options.certificate_authority {
+            if self.embed_certs {
+                cluster.certificate_authority_data = Some(embed(path)?);
+                cluster.certificate_authority = None;
+            } else {
+                cluster.certificate_authority = Some(absolute(path)?);
+                cluster.certificate_authority_data = None;
+            }
+            cluster.insecure_skip_tls_verify = None;
+        }
+        if let Some(tls_server_name) = &options.tls_server_name {
+            cluster.tls_server_name = Some(tls_server_name.clone());
+        }
+        if let Some(proxy_url) = &self.proxy_url {
+            cluster.proxy_url = Some(proxy_url.clone());
+        }
+        if options.insecure_skip_tls_verify {
+            cluster.insecure_skip_tls_verify = Some(true);
+            cluster.certificate_authority = None;
+            cluster.certificate_authority_data = None;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct DeleteCluster {
+    /// Cluster to delete
+    name: String,
+}
+
+impl DeleteCluster {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        delete::<NamedCluster>(context, KIND, &self.name)
+    }
+}
@@ -0,0 +1,475 @@
+use kube::config::NamedContext;
+use tabled::settings::object::Rows;
+
+use super::*;
+
+const KIND: &str = "context";
+
+#[derive(Clone, Debug, Args)]
+pub struct GetContexts {
+    /// Contexts to describe, all of them when none is given
+    names: Vec<String>,
+
+    /// When using the default output format, don't print headers
+    #[arg(long)]
+    no_headers: bool,
+}
+
+impl GetContexts {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let current = kubeapi.current_context();
+        let contexts = kubeapi
+            .get_contexts()
+            .iter()
+            .filter(|ctx| self.names.is_empty() || self.names.contains(&ctx.name))
+            .collect::<Vec<_>>();
+        if let Some(name) = self
+            .names
+            .iter()
+            .find(|name| contexts.iter().all(|ctx| &ctx.name != *name))
+        {
+            return Err(not_found(KIND, name));
+        }
+
+        if matches!(context.output_deprecated(), OutputFormat::Name) {
+            contexts
+                .iter()
+                .for_each(|ctx| context.ui().print(&ctx.name));
+            return Ok(());
+        }
+
+        context.ui().print(self.table(contexts, current));
+        Ok(())
+    }
+
+    fn table<'a>(
+        &self,
+        contexts: impl IntoIterator<Item = &'a NamedContext>,
+        current: Option<&str>,
+    ) -> tabled::Table {
+        let rows = contexts
+            .into_iter()
+            .map(|ctx| ContextRow::new(ctx, current));
+        let mut table = tabled::Table::new(rows);
+        table.with(Style::blank()).with(Padding::new(0, 2, 0, 0));
+        if self.no_headers {
+            table.with(Remove::row(Rows::first()));
+        }
+        table
+    }
+}
+
+#[derive(Debug, tabled::Tabled)]
+#[tabled(rename_all = "UPPERCASE")]
+struct ContextRow {
+    current: &'static str,
+    name: String,
+    cluster: String,
+    authinfo: String,
+    namespace: String,
+}
+
+impl ContextRow {
+    fn new(ctx: &NamedContext, current: Option<&str>) -> Self {
+        let current = if current == Some(ctx.name.as_str()) {
+            "*"
+        } else {
+            ""
+        };
+        let body = ctx.context.as_ref();
+        Self {
+            current,
+            name: ctx.name.clone(),
+            cluster: body.map(|body| body.cluster.clone()).unwrap_or_default(),
+            authinfo: body.and_then(|body| body.user.clone()).unwrap_or_default(),
+            namespace: body
+                .and_then(|body| body.namespace.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct UseContext {
+    /// Context to switch to
+    name: String,
+}
+
+impl UseContext {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let mut kubeconfig = context.kubeapi().kubeconfig_files().clone();
+        if kubeconfig.get::<NamedContext>(&self.name).is_none() {
+            return Err(not_found(KIND, &self.name));
+        }
+        kubeconfig.set_current_context(Some(&self.name))?;
+        kubeconfig.save()?;
+        context
+            .ui()
+            .print(format!("Switched to context \"{}\".", self.name));
+        Ok(())
+    }
+}
+
+/// The cluster, user and namespace come from the global `--cluster`, `--user`
+/// and `--namespace` flags, which would otherwise clash with options of the same name
+#[derive(Clone, Debug, Args)]
+pub struct SetContext {
+    /// Context to create or modify
+    #[arg(required_unless_present = "current")]
+    name: Option<String>,
+
+    /// Modify the current context
+    #[arg(long, conflicts_with = "name")]
+    current: bool,
+}
+
+impl SetContext {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let mut kubeconfig = kubeapi.kubeconfig_files().clone();
+        let name = match self.name {
+            Some(name) => name,
+            None => kubeconfig
+                .current_context()
+                .map(ToString::to_string)
+                .ok_or(RkError::NoCurrentContext)?,
+        };
+
+        let existing = kubeconfig.get::<NamedContext>(&name);
+        let verb = if existing.is_some() {
+            "modified"
+        } else {
+            "created"
+        };
+        let mut named = existing.unwrap_or_else(|| NamedContext {
+            name: name.clone(),
+            context: None,
+        });
+        let body = named.context.get_or_insert_default();
+        let options = kubeapi.kubeconfig_options();
+        if let Some(cluster) = &options.cluster {
+            body.cluster.clone_from(cluster);
+        }
+        if let Some(user) = &options.user {
+            body.user = Some(user.clone());
+        }
+        if let Some(namespace) = kubeapi.namespace().namespace() {
+            body.namespace = Some(namespace);
+        }
+
+        kubeconfig.upsert(named)?;
+        kubeconfig.save()?;
+        context.ui().print(format!("Context \"{name}\" {verb}."));
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct DeleteContext {
+    /// Context to delete
+    name: String,
+}
+
+impl DeleteContext {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        delete::<NamedContext>(context, KIND, &self.name)?;
+        if context.kubeapi().current_context() == Some(self.name.as_str()) {
+            context.ui().notice(
+                "warning: this removed your active context, use \"config use-context\" to select a different one",
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct RenameContext {
+    /// Context to rename
+    old_name: String,
+
+    /// New name of the context
+    new_name: String,
+}
+
+impl RenameContext {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let mut kubeconfig = context.kubeapi().kubeconfig_files().clone();
+        if kubeconfig.get::<NamedContext>(&self.new_name).is_some() {
+            return Err(RkError::AlreadyInKubeconfig {
+                kind: KIND,
+                name: self.new_name,
+            });
+        }
+        if !kubeconfig.rename::<NamedContext>(&self.old_name, &self.new_name)? {
+            return Err(not_found(KIND, &self.old_name));
+        }
+        // Keep pointing at the same context
+        if kubeconfig.current_context() == Some(self.old_name.as_str()) {
+            kubeconfig.set_current_context(Some(&self.new_name))?;
+        }
+        kubeconfig.save()?;
+        context.ui().print(format!(
+            "Context \"{}\" renamed to \"{}\".",
+            self.old_name, self.new_name
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use rkubectl_kubeapi::KubeconfigFiles;
+
+    use super::*;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: dev
+clusters:
+- name: dev-cluster
+  cluster:
+    server: https://dev.example.com
+- name: prod-cluster
+  cluster:
+    server: https://prod.example.com
+contexts:
+- name: dev
+  context:
+    cluster: dev-cluster
+    user: dev-user
+    namespace: web
+- name: prod
+  context:
+    cluster: prod-cluster
+    user: prod-user
+users:
+- name: dev-user
+  user:
+    token: dev
+- name: prod-user
+  user:
+    token: prod
+"#;
+
+    /// Kubeconfig file in its own temporary directory
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("rkubectl-context-{name}-{}", process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("config"), KUBECONFIG).unwrap();
+            Self(dir)
+        }
+
+        fn context(&self) -> Context {
+            self.context_with(default(), Namespace::Default)
+        }
+
+        fn context_with(&self, options: KubeConfigOptions, namespace: Namespace) -> Context {
+            let options = KubeConfigOptions {
+                kubeconfig: Some(self.0.join("config")),
+                ..options
+            };
+            let kubeapi = Kubeapi::offline(&options, false)
+                .unwrap()
+                .with_namespace(namespace);
+            Context::new(kubeapi, Ui::new(OutputFormat::Normal))
+        }
+
+        fn load(&self) -> KubeconfigFiles {
+            KubeconfigFiles::load(Some(&self.0.join("config"))).unwrap()
+        }
+
+        fn context_named(&self, name: &str) -> Option<kube::config::Context> {
+            self.load().get::<NamedContext>(name)?.context
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[derive(Debug, clap::Parser)]
+    struct Test {
+        #[command(flatten)]
+        set_context: SetContext,
+    }
+
+    fn set_context(args: &[&str]) -> SetContext {
+        let args = iter::once("set-context").chain(args.iter().copied());
+        Test::parse_from(args).set_context
+    }
+
+    #[test]
+    fn use_context() {
+        let fixture = Fixture::new("use");
+        UseContext {
+            name: "prod".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap();
+        assert_eq!(fixture.load().current_context(), Some("prod"));
+
+        let err = UseContext {
+            name: "staging".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap_err();
+        assert!(matches!(err, RkError::NotInKubeconfig { .. }));
+        assert_eq!(fixture.load().current_context(), Some("prod"));
+    }
+
+    #[test]
+    fn set_context_create() {
+        let fixture = Fixture::new("set-create");
+        let options = KubeConfigOptions {
+            cluster: Some("prod-cluster".to_string()),
+            user: Some("dev-user".to_string()),
+            ..default()
+        };
+        let namespace = Namespace::Namespace("batch".to_string());
+        set_context(&["staging"])
+            .exec(&fixture.context_with(options, namespace))
+            .unwrap();
+
+        let staging = fixture.context_named("staging").unwrap();
+        assert_eq!(staging.cluster, "prod-cluster");
+        assert_eq!(staging.user.as_deref(), Some("dev-user"));
+        assert_eq!(staging.namespace.as_deref(), Some("batch"));
+        assert_eq!(fixture.load().current_context(), Some("dev"));
+    }
+
+    #[test]
+    fn set_context_modify() {
+        let fixture = Fixture::new("set-modify");
+        let namespace = Namespace::Namespace("api".to_string());
+        set_context(&["prod"])
+            .exec(&fixture.context_with(default(), namespace))
+            .unwrap();
+
+        // Only the given fields change
+        let prod = fixture.context_named("prod").unwrap();
+        assert_eq!(prod.cluster, "prod-cluster");
+        assert_eq!(prod.user.as_deref(), Some("prod-user"));
+        assert_eq!(prod.namespace.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn set_context_current() {
+        let fixture = Fixture::new("set-current");
+        let options = KubeConfigOptions {
+            user: Some("prod-user".to_string()),
+            ..default()
+        };
+        set_context(&["--current"])
+            .exec(&fixture.context_with(options, Namespace::Default))
+            .unwrap();
+
+        let dev = fixture.context_named("dev").unwrap();
+        assert_eq!(dev.cluster, "dev-cluster");
+        assert_eq!(dev.user.as_deref(), Some("prod-user"));
+        assert_eq!(dev.namespace.as_deref(), Some("web"));
+
+        let args = ["set-context", "dev", "--current"];
+        let err = Test::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn delete_context() {
+        let fixture = Fixture::new("delete");
+        DeleteContext {
+            name: "prod".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap();
+        let kubeconfig = fixture.load();
+        assert!(kubeconfig.get::<NamedContext>("prod").is_none());
+        assert!(kubeconfig.get::<NamedContext>("dev").is_some());
+
+        let err = DeleteContext {
+            name: "prod".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap_err();
+        assert!(matches!(err, RkError::NotInKubeconfig { .. }));
+    }
+
+    #[test]
+    fn rename_context() {
+        let fixture = Fixture::new("rename");
+        RenameContext {
+            old_name: "prod".to_string(),
+            new_name: "production".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap();
+        let kubeconfig = fixture.load();
+        assert!(kubeconfig.get::<NamedContext>("prod").is_none());
+        let production = kubeconfig.get::<NamedContext>("production").unwrap();
+        assert_eq!(production.context.unwrap().cluster, "prod-cluster");
+        assert_eq!(kubeconfig.current_context(), Some("dev"));
+
+        // Renaming the current context keeps it current
+        RenameContext {
+            old_name: "dev".to_string(),
+            new_name: "development".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap();
+        assert_eq!(fixture.load().current_context(), Some("development"));
+
+        let err = RenameContext {
+            old_name: "development".to_string(),
+            new_name: "production".to_string(),
+        }
+        .exec(&fixture.context())
+        .unwrap_err();
+        assert!(matches!(err, RkError::AlreadyInKubeconfig { .. }));
+    }
+
+    #[test]
+    fn get_contexts() {
+        let fixture = Fixture::new("get");
+        let kubeconfig = fixture.load();
+        let contexts = kubeconfig.merged().contexts.iter();
+        let get = GetContexts {
+            names: vec![],
+            no_headers: false,
+        };
+        let table = get.table(contexts.clone(), kubeconfig.current_context());
+        let lines = table.to_string();
+        let lines = lines.lines().map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "CURRENT   NAME   CLUSTER        AUTHINFO    NAMESPACE",
+                "*         dev    dev-cluster    dev-user    web",
+                "          prod   prod-cluster   prod-user",
+            ]
+        );
+
+        let get = GetContexts {
+            names: vec![],
+            no_headers: true,
+        };
+        let table = get.table(contexts, Some("prod")).to_string();
+        let lines = table.lines().map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "    dev    dev-cluster    dev-user    web",
+                "*   prod   prod-cluster   prod-user",
+            ]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+
+use kube::config::AuthInfo;
+use kube::config::AuthProviderConfig;
+use kube::config::ExecConfig;
+use kube::config::NamedAuthInfo;
+
+use super::*;
+
+const KIND: &str = "user";
+
+/// The bearer token comes from the global `--token` flag,
+/// which would otherwise clash with an option of the same name
+#[derive(Clone, Debug, Args)]
+pub struct SetCredentials {
+    /// User to create or modify
+    name: String,
+
+    /// Path to the client certificate file
+    #[arg(long)]
+    client_certificate: Option<PathBuf>,
+
+    /// Path to the client key file
+    #[arg(long)]
+    client_key: Option<PathBuf>,
+
+    /// Store the client certificate and key data in kubeconfig instead of the paths to them
+    #[arg(long)]
+    embed_certs: bool,
+
+    /// Username for basic authentication
+    #[arg(long)]
+    username: Option<String>,
+
+    /// Password for basic authentication
+    #[arg(long)]
+    password: Option<String>,
+
+    /// Auth provider for the user (e.g. oidc)
+    #[arg(long)]
+    auth_provider: Option<String>,
+
+    /// Configuration for the auth provider, KEY- removes the key, this flag can be repeated
+    #[arg(long, value_name = "KEY=VALUE", value_parser = KeyValue::<Option<String>>::removable_value_parser())]
+    auth_provider_arg: Vec<KeyValue<Option<String>>>,
+
+    /// Command of the exec credential plugin
+    #[arg(long, requires = "exec_api_version")]
+    exec_command: Option<String>,
+
+    /// API version of the exec credential plugin
+    #[arg(long)]
+    exec_api_version: Option<String>,
+
+    /// Arguments of the exec credential plugin, this flag can be repeated
+    #[arg(long)]
+    exec_arg: Option<Vec<String>>,
+
+    /// Environment variable of the exec credential plugin, NAME- removes the variable,
+    /// this flag can be repeated
+    #[arg(long, value_name = "NAME=VALUE", value_parser = KeyValue::<Option<String>>::removable_value_parser())]
+    exec_env: Vec<KeyValue<Option<String>>>,
+}
+
+impl SetCredentials {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        let kubeapi = context.kubeapi();
+        let token = kubeapi.kubeconfig_options().token.as_deref();
+        if token.is_some() && (self.username.is_some() || self.password.is_some()) {
+            return Err(RkError::ConflictingAuthentication);
+        }
+
+        let mut kubeconfig = kubeapi.kubeconfig_files().clone();
+        let mut named = kubeconfig
+            .get::<NamedAuthInfo>(&self.name)
+            .unwrap_or_else(|| NamedAuthInfo {
+                name: self.name.clone(),
+                auth_info: None,
+            });
+        self.apply(token, named.auth_info.get_or_insert_default())?;
+        kubeconfig.upsert(named)?;
+        kubeconfig.save()?;
+        context.ui().print(format!("User \"{}\" set.", self.name));
+        Ok(())
+    }
+
+    fn apply(&self, token: Option<&str>, auth_info: &mut AuthInfo) -> RkResult<()> {
+        if let Some(token) = token {
+            auth_info.token = Some(token.to_string().into());
+        }
+        if let Some(path) = &self.client_certificate {
+            if self.embed_certs {
+                auth_info.client_certificate_data = Some(embed(path)?);
+                auth_info.client_certificate = None;
+            } else {
+                auth_info.client_certificate = Some(absolute(path)?);
+                auth_info.client_certificate_data = None;
+            }
+        }
+        if let Some(path) = &self.client_key {
+            if self.embed_certs {
+                auth_info.client_key_data = Some(embed(path)?.into());
+                auth_info.client_key = None;
+            } else {
+                auth_info.client_key = Some(absolute(path)?);
+                auth_info.client_key_data = None;
+            }
+        }
+        if let Some(username) = &self.username {
+            auth_info.username = Some(username.clone());
+        }
+        if let Some(password) = &self.password {
+            auth_info.password = Some(password.clone().into());
+        }
+        self.apply_auth_provider(auth_info);
+        self.apply_exec(auth_info);
+        Ok(())
+    }
+
+    /// Switching to another provider starts over, arguments add to the existing configuration
+    fn apply_auth_provider(&self, auth_info: &mut AuthInfo) {
+        if let Some(name) = &self.auth_provider
+            && auth_info
+                .auth_provider
+                .as_ref()
+                .is_none_or(|provider| &provider.name != name)
+        {
+            auth_info.auth_provider = Some(AuthProviderConfig {
+                name: name.clone(),
+                config: default(),
+            });
+        }
+        if let Some(provider) = &mut auth_info.auth_provider {
+            for (key, value) in self.auth_provider_arg.iter().map(KeyValue::as_pair) {
+                match value {
+                    Some(value) => provider.config.insert(key.clone(), value.clone()),
+                    None => provider.config.remove(key),
+                };
+            }
+        }
+    }
+
+    fn apply_exec(&self, auth_info: &mut AuthInfo) {
+        if self.exec_command.is_none()
+            && self.exec_api_version.is_none()
+            && self.exec_arg.is_none()
+            && self.exec_env.is_empty()
+        {
+            return;
+        }
+
+        let exec = auth_info.exec.get_or_insert(ExecConfig {
+            api_version: None,
+            command: None,
+            args: None,
+            env: None,
+            drop_env: None,
+            interactive_mode: None,
+            provide_cluster_info: false,
+            cluster: None,
+        });
+        if let Some(command) = &self.exec_command {
+            exec.command = Some(command.clone());
+        }
+        if let Some(api_version) = &self.exec_api_version {
+            exec.api_version = Some(api_version.clone());
+        }
+        if let Some(args) = &self.exec_arg {
+            exec.args = Some(args.clone());
+        }
+        if !self.exec_env.is_empty() {
+            let env = exec.env.get_or_insert_default();
+            for (name, value) in self.exec_env.iter().map(KeyValue::as_pair) {
+                let Some(value) = value else {
+                    env.retain(|var| var.get("name") != Some(name));
+                    continue;
+                };
+                let var = HashMap::from([
+                    ("name".to_string(), name.clone()),
+                    ("value".to_string(), value.clone()),
+                ]);
+                match env.iter_mut().find(|var| var.get("name") == Some(name)) {
+                    Some(existing) => *existing = var,
+                    None => env.push(var),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct DeleteUser {
+    /// User to delete
+    name: String,
+}
+
+impl DeleteUser {
+    pub fn exec(self, context: &Context) -> RkResult<()> {
+        delete::<NamedAuthInfo>(context, KIND, &self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, clap::Parser)]
+    struct Test {
+        #[command(flatten)]
+        set_credentials: SetCredentials,
+    }
+
+    fn set_credentials(args: &[&str]) -> SetCredentials {
+        let args = iter::once("set-credentials").chain(args.iter().copied());
+        Test::parse_from(args).set_credentials
+    }
+
+    #[test]
+    fn exec_plugin() {
+        let mut auth_info = AuthInfo::default();
+        set_credentials(&[
+            "dev",
+            "--exec-command=aws",
+            "--exec-api-version=client.authentication.k8s.io/v1",
+            "--exec-arg=eks",
+            "--exec-arg=get-token",
+            "--exec-env=AWS_PROFILE=dev",
+        ])
+        .apply(None, &mut auth_info)
+        .unwrap();
+        set_credentials(&["dev", "--exec-env=AWS_PROFILE=prod", "--exec-env=DEBUG=1"])
+            .apply(None, &mut auth_info)
+            .unwrap();
+
+        let exec = auth_info.exec.as_ref().unwrap();
+        assert_eq!(exec.command.as_deref(), Some("aws"));
+        assert_eq!(exec.args.as_deref().unwrap(), ["eks", "get-token"]);
+        let env = exec.env.as_deref().unwrap();
+        assert_eq!(env.len(), 2);
+        assert_eq!(env[0]["value"], "prod");
+        assert_eq!(env[1]["name"], "DEBUG");
+
+        set_credentials(&["dev", "--exec-env=AWS_PROFILE-"])
+            .apply(None, &mut auth_info)
+            .unwrap();
+        let env = auth_info.exec.unwrap().env.unwrap();
+        assert_eq!(env.len(), 1);
+        assert_eq!(env[0]["name"], "DEBUG");
+    }
+
+    #[test]
+    fn exec_command_without_api_version() {
+        let args = ["set-credentials", "dev", "--exec-command=aws"];
+        let err = Test::try_parse_from(args).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn auth_provider() {
+        let mut auth_info = AuthInfo::default();
+        set_credentials(&[
+            "dev",
+            "--auth-provider=oidc",
+            "--auth-provider-arg=client-id=rk",
+        ])
+        .apply(Some("token"), &mut auth_info)
+        .unwrap();
+        set_credentials(&["dev", "--auth-provider-arg=idp-issuer-url=https://issuer"])
+            .apply(None, &mut auth_info)
+            .unwrap();
+
+        assert!(auth_info.token.is_some());
+        let provider = auth_info.auth_provider.as_ref().unwrap();
+        assert_eq!(provider.name, "oidc");
+        assert_eq!(provider.config.len(), 2);
+        assert_eq!(provider.config["client-id"], "rk");
+
+        set_credentials(&["dev", "--auth-provider-arg=client-id-"])
+            .apply(None, &mut auth_info)
+            .unwrap();
+        let provider = auth_info.auth_provider.unwrap();
+        assert_eq!(provider.config.len(), 1);
+        assert!(!provider.config.contains_key("client-id"));
+    }
+}
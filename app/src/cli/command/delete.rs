@@ -171,6 +171,9 @@ pub struct Delete {
     #[arg(long)]
     all: bool,
 
+    #[command(flatten)]
+    selectors: Selectors,
+
     /// If true, wait for resources to be gone before returning.
     /// This waits for finalizers.
     #[arg(long, default_value_t = true)]
@@ -182,46 +185,53 @@ pub struct Delete {
 
 impl Delete {
     pub async fn exec(self, context: &Context) -> RkResult<()> {
-        let kubeapi = context.kubeapi();
+        let kubeapi = &context
+            .kubeapi()
+            .clone()
+            .with_selectors(self.selectors.clone())
+            .discover()
+            .await?;
         if let Some(filename) = &self.filename {
             let dp = api::DeleteParams::default();
             todo!("Deleting from {filename} ({dp:?}");
         } else {
-            self.delete_resources(kubeapi).await?;
+            self.delete_resources(context, kubeapi).await?;
         }
         Ok(())
     }
 
-    async fn delete_resources(&self, kubeapi: &Kubeapi) -> kube::Result<()> {
+    async fn delete_resources(&self, context: &Context, kubeapi: &Kubeapi) -> RkResult<()> {
         let dp = kubeapi.delete_params(self.cascade, self.dry_run);
         for resource in self.resources(kubeapi)? {
             if self.dry_run == DryRun::Client {
                 println!("{resource} deleted (dry run)");
             } else {
-                resource
+                let deleted = resource
                     .delete(kubeapi, &dp, self.all)
                     .await
                     .or_else(|err| self.ignore_not_found(err))?;
+                for deleted in deleted {
+                    context.ui().show(deleted, &default())?;
+                }
             }
         }
 
         Ok(())
     }
 
-    fn resources(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<ResourceArg>> {
+    fn resources(&self, kubeapi: &Kubeapi) -> Result<Vec<ResourceArg>, ResourceError> {
         let resources = self.resources.as_deref().unwrap_or_default();
         ResourceArg::from_strings(resources, kubeapi)
-            .map_err(|_err| kube::Error::LinesCodecMaxLineLengthExceeded)
     }
 
-    fn ignore_not_found(&self, err: kube::Error) -> kube::Result<()> {
+    fn ignore_not_found<T: Default>(&self, err: kube::Error) -> kube::Result<T> {
         if self.ignore_not_found
             && matches!(
                 err,
                 kube::Error::Api(kube::error::ErrorResponse { code: 404, .. })
             )
         {
-            Ok(())
+            Ok(T::default())
         } else {
             Err(err)
         }
@@ -1,3 +1,6 @@
+use futures_util::StreamExt;
+use futures_util::stream;
+
 use super::*;
 
 /// Display one or many resources
@@ -17,10 +20,31 @@ pub struct Get {
     #[command(flatten)]
     params: ShowParams,
 
+    #[command(flatten)]
+    selectors: Selectors,
+
     /// Raw URI to request from the server.  Uses the transport specified by the kubeconfig file.
     #[arg(long, conflicts_with = "regular")]
     raw: Option<String>,
 
+    /// After listing/getting the requested object, watch for changes.
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Watch for changes to the requested object(s), without listing/getting first.
+    #[arg(long)]
+    watch_only: bool,
+
+    /// Output watch event objects when --watch or --watch-only is used.
+    /// Existing objects are output as initial ADDED events.
+    #[arg(long)]
+    output_watch_events: bool,
+
+    /// If true, have the server return the appropriate table output.
+    /// Supports extension APIs and CRDs.
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    server_print: bool,
+
     /// If specified, gets the subresource of the requested object.
     #[arg(group = "regular", long)]
     subresource: Option<String>,
@@ -32,29 +56,74 @@ pub struct Get {
 
 impl Get {
     pub async fn exec(&self, context: &Context) -> RkResult<()> {
-        let kubeapi = context.kubeapi();
+        let output = context.output_deprecated();
+        let server_print = self.server_print && output.is_table();
+        let kubeapi = context
+            .kubeapi()
+            .clone()
+            .with_selectors(self.selectors.clone())
+            .with_server_print(server_print)
+            .with_server_print_objects(self.params.sort_by.is_some());
         if let Some(raw) = self.raw.as_deref() {
             let name = raw.strip_prefix("/").unwrap_or(raw);
             let text = kubeapi.raw_get(name).await?;
             println!("{text}");
         } else {
-            let resources = self.resources(kubeapi)?;
-            let mut params = self.params;
-            params.show_kind |= resources.len() > 1;
+            let (kubeapi, resources, category) = self.resources(kubeapi).await?;
+            let kubeapi = &kubeapi;
+            let mut params = self.params.clone();
+            if self.watch || self.watch_only {
+                return self.watch(kubeapi, &resources, &params, output).await;
+            }
+            // Categories such as `all` may expand to a single kind, show it anyway
+            params.show_kind |= category || resources.len() > 1;
             let namespace = kubeapi.show_namespace();
-            for resource in resources {
-                let data = resource.get(kubeapi).await?;
-                let output = context.output_deprecated();
-                println!("{}", data.output(namespace, &params, output));
+            // Fetch concurrently, print in the order asked for
+            let mut data = stream::iter(&resources)
+                .map(|resource| resource.get(kubeapi))
+                .buffered(kubeapi.max_concurrent_requests());
+            while let Some(data) = data.next().await {
+                println!("{}", data?.output(namespace, &params, output)?);
             }
         }
         Ok(())
     }
 
-    fn resources(&self, kubeapi: &Kubeapi) -> kube::Result<Vec<ResourceArg>> {
+    async fn watch(
+        &self,
+        kubeapi: &Kubeapi,
+        resources: &[ResourceArg],
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> RkResult<()> {
+        let [resource] = resources else {
+            return Err(RkError::WatchMultipleResources(resources.len()));
+        };
+        let mut printer = WatchPrinter::new(kubeapi.show_namespace(), self.output_watch_events);
+        let mut events = resource.watch(kubeapi, self.watch_only)?;
+        while let Some(events) = events.next().await {
+            match events {
+                Ok(events) if events.is_empty() => {}
+                Ok(events) => println!("{}", printer.print(&events, params, output)?),
+                Err(err) => warn!(%err, "Watch interrupted, resuming"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Discovery only runs when some resource cannot be resolved without it
+    async fn resources(&self, kubeapi: Kubeapi) -> RkResult<(Kubeapi, Vec<ResourceArg>, bool)> {
         let resources = self.resources.as_deref().unwrap_or_default();
-        ResourceArg::from_strings(resources, kubeapi)
-            .inspect(|resources| info!(args=?self.resources, ?resources))
-            .map_err(|_err| kube::Error::LinesCodecMaxLineLengthExceeded)
+        let (kubeapi, expanded) = match ResourceArg::expand_strings(resources, &kubeapi) {
+            Err(ResourceError::Unknown { .. }) => {
+                let kubeapi = kubeapi.discover().await?;
+                let expanded = ResourceArg::expand_strings(resources, &kubeapi);
+                (kubeapi, expanded)
+            }
+            expanded => (kubeapi, expanded),
+        };
+        let (resources, category) = expanded?;
+        info!(args=?self.resources, ?resources, category);
+        Ok((kubeapi, resources, category))
     }
 }
@@ -89,6 +89,12 @@ fn resource_names<'a>(
     ordered
 }
 
+impl serde::Serialize for Resources {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.resources.iter().zip(&self.values))
+    }
+}
+
 impl Show for Resources {
     fn header(&self, _output: &OutputFormat) -> Vec<String> {
         self.resources.clone()
@@ -98,23 +104,7 @@ impl Show for Resources {
         self.values.clone()
     }
 
-    fn normal(&self, _params: &ShowParams, _output: &OutputFormat) -> tabled::Table {
-        todo!("Not supported on Resources for normal")
-    }
-
-    fn wide(&self, _params: &ShowParams, _output: &OutputFormat) -> tabled::Table {
-        todo!("Not supported on Resources for wide")
-    }
-
-    fn yaml(&self, _params: &ShowParams) -> String {
-        todo!("Not supported on Resources for yaml")
-    }
-
-    fn json(&self, _params: &ShowParams) -> String {
-        todo!("Not supported on Resources for json")
-    }
-
-    fn name(&self) -> String {
-        String::from("resources")
+    fn name(&self) -> Result<String, ShowError> {
+        Ok(String::from("resources"))
     }
 }
@@ -22,3 +22,12 @@ impl Auth {
         }
     }
 }
+
+/// Impersonation changes every answer, so always say who was asked about
+fn impersonation_notice(context: &Context) {
+    if let Some(impersonation) = context.kubeapi().impersonation() {
+        context
+            .ui()
+            .notice(format!("Impersonating {impersonation}"));
+    }
+}
@@ -24,28 +24,28 @@ enum Object {
 
 impl CanI {
     pub async fn ask(self, context: &Context) -> RkResult<()> {
-        let kubeapi = context.kubeapi();
-        let ssar = Object::from_text(self.object, kubeapi)
-            .map_err(|_err| kube::Error::LinesCodecMaxLineLengthExceeded)?
+        let kubeapi = &context.kubeapi().clone().discover().await?;
+        let ssar = Object::from_text(self.object, kubeapi)?
             .ask(kubeapi, &self.verb)
             .await?;
+        impersonation_notice(context);
 
         let show_params = default();
         let output = context.output_deprecated();
-        println!("{}", ssar.output(false, &show_params, output));
+        println!("{}", ssar.output(false, &show_params, output)?);
         Ok(())
     }
 }
 
 impl Object {
-    fn from_text(text: String, kubeapi: &Kubeapi) -> Result<Self, InvalidResourceSpec> {
+    fn from_text(text: String, kubeapi: &Kubeapi) -> Result<Self, ResourceError> {
         if text.starts_with("/") {
             Ok(Self::NonResourceUrl(text))
         } else {
             ResourceArg::from_strings(&[text], kubeapi)?
                 .pop()
                 .map(Self::Resource)
-                .ok_or(InvalidResourceSpec)
+                .ok_or_else(|| InvalidResourceSpec.into())
         }
     }
 
@@ -15,9 +15,35 @@ impl WhoAmI {
             .await
             .inspect(|k| kubeapi.inspect(k))
             .inspect_err(|err| kubeapi.inspect_err(err))?;
+        impersonation_notice(context);
+        Self::check_impersonation(kubeapi, &ssr)?;
         let show_params = default();
         let output = context.output_deprecated();
-        println!("{}", ssr.output(false, &show_params, output));
+        println!("{}", ssr.output(false, &show_params, output)?);
         Ok(())
     }
+
+    /// The review reports who the server thinks we are, which must be whoever we impersonate
+    fn check_impersonation(
+        kubeapi: &Kubeapi,
+        ssr: &authenticationv1::SelfSubjectReview,
+    ) -> RkResult<()> {
+        let Some(requested) = kubeapi.impersonate_user() else {
+            return Ok(());
+        };
+        let actual = ssr
+            .status
+            .as_ref()
+            .and_then(|status| status.user_info.as_ref())
+            .and_then(|info| info.username.as_deref())
+            .unwrap_or_default();
+        if actual == requested {
+            Ok(())
+        } else {
+            Err(RkError::ImpersonationIgnored {
+                requested: requested.to_string(),
+                actual: actual.to_string(),
+            })
+        }
+    }
 }
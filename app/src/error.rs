@@ -6,6 +6,46 @@ pub enum RkError {
     Io(#[from] io::Error),
     #[error(transparent)]
     Kube(#[from] kube::Error),
+    #[error(transparent)]
+    Kubeconfig(#[from] kube::config::KubeconfigError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Show(#[from] ShowError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Resource(#[from] ResourceError),
+    #[error(
+        "watch is only supported on individual resources and resource collections, but {0} resources were found"
+    )]
+    WatchMultipleResources(usize),
+    #[error("the server rejected impersonating {impersonation}")]
+    #[diagnostic(help(
+        "make sure you are allowed the `impersonate` verb on the users, groups and uids requested"
+    ))]
+    ImpersonationRejected {
+        impersonation: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+    #[error("asked to impersonate \"{requested}\", but the server authenticated \"{actual}\"")]
+    ImpersonationIgnored { requested: String, actual: String },
+    #[error("no {kind} exists with the name: \"{name}\"")]
+    NotInKubeconfig { kind: &'static str, name: String },
+    #[error("the {kind} \"{name}\" already exists")]
+    AlreadyInKubeconfig { kind: &'static str, name: String },
+    #[error("could not read {}", path.display())]
+    ReadFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error(
+        "you cannot specify more than one authentication method at the same time: --token, --username/--password"
+    )]
+    ConflictingAuthentication,
+    #[error("no current context is set")]
+    #[diagnostic(help("use \"config use-context\" to select one"))]
+    NoCurrentContext,
     #[error("Not implemented yet")]
     #[diagnostic(help("This functionality is not implemented yet"))]
     NotImplemented,
@@ -15,4 +55,47 @@ impl RkError {
     pub fn todo() -> Self {
         Self::NotImplemented
     }
+
+    /// Single out failures caused by the impersonation headers,
+    /// they are easy to miss among other permission errors
+    pub fn with_impersonation(self, impersonation: Option<String>) -> Self {
+        match (self, impersonation) {
+            (Self::Kube(kube::Error::Api(response)), Some(impersonation))
+                if response.code == 403 && response.message.contains("cannot impersonate") =>
+            {
+                let source = Box::new(kube::Error::Api(response));
+                Self::ImpersonationRejected {
+                    impersonation,
+                    source,
+                }
+            }
+            (err, _) => err,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impersonation_rejected() {
+        let forbidden = || {
+            RkError::Kube(kube::Error::Api(kube::error::ErrorResponse {
+                status: "Failure".to_string(),
+                message: r#"users "bob" is forbidden: User "alice" cannot impersonate resource "users" in API group "" at the cluster scope"#.to_string(),
+                reason: "Forbidden".to_string(),
+                code: 403,
+            }))
+        };
+
+        let err = forbidden().with_impersonation(Some(r#"user "bob""#.to_string()));
+        assert_eq!(
+            err.to_string(),
+            r#"the server rejected impersonating user "bob""#
+        );
+
+        let err = forbidden().with_impersonation(None);
+        assert!(matches!(err, RkError::Kube(_)));
+    }
 }
@@ -13,6 +13,7 @@ use serde_json as json;
 use tracing::debug;
 use tracing::info;
 use tracing::trace;
+use tracing::warn;
 
 use k8s::authenticationv1;
 use k8s::authorizationv1;
@@ -27,12 +28,16 @@ use rkubectl_kubeapi::KubeConfigOptions;
 use rkubectl_kubeapi::Kubeapi;
 use rkubectl_kubeapi::KubeapiOptions;
 use rkubectl_kubeapi::Namespace;
+use rkubectl_kubeapi::Selectors;
 use rkubectl_resource::InvalidResourceSpec;
 use rkubectl_resource::ResourceArg;
+use rkubectl_resource::ResourceError;
 use rkubectl_ui::OutputFormat;
 use rkubectl_ui::Show;
+use rkubectl_ui::ShowError;
 use rkubectl_ui::ShowParams;
 use rkubectl_ui::Ui;
+use rkubectl_ui::WatchPrinter;
 
 pub use cli::*;
 pub use error::RkError;
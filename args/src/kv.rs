@@ -64,6 +64,27 @@ impl KeyValue<String> {
     }
 }
 
+impl KeyValue<Option<String>> {
+    /// Like `key=value`, `key-` is accepted as well and has no value
+    pub fn removable_value_parser() -> impl TypedValueParser {
+        StringValueParser::new().try_map(Self::from_text_or_removal)
+    }
+
+    fn from_text_or_removal(text: impl AsRef<str>) -> Result<Self, String> {
+        let text = text.as_ref();
+        match text.strip_suffix('-') {
+            Some(key) if !text.contains(KeyValue::<String>::DELIMITER) => Ok(Self {
+                key: key.to_string(),
+                value: None,
+            }),
+            _ => KeyValue::<String>::from_text(text).map(|KeyValue { key, value }| Self {
+                key,
+                value: Some(value),
+            }),
+        }
+    }
+}
+
 impl KeyValue<Vec<u8>> {
     pub fn byte_string(self) -> KeyValue<k8s::ByteString> {
         let Self { key, value } = self;
@@ -138,6 +159,17 @@ mod tests {
         assert_eq!(err, "Invalid key=value: no '=' in aaa-bbb");
     }
 
+    #[test]
+    fn removal() {
+        let kv = KeyValue::<Option<String>>::from_text_or_removal("aaa-").unwrap();
+        assert_eq!(kv.key, "aaa");
+        assert_eq!(kv.value, None);
+        let kv = KeyValue::<Option<String>>::from_text_or_removal("aaa=bbb-").unwrap();
+        assert_eq!(kv.key, "aaa");
+        assert_eq!(kv.value.as_deref(), Some("bbb-"));
+        let _err = KeyValue::<Option<String>>::from_text_or_removal("aaa").unwrap_err();
+    }
+
     #[test]
     fn from_lines() {
         let kv = KeyValue::from_lines("aaa=bbb\nccc=ddd\n").unwrap();
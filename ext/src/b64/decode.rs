@@ -16,3 +16,10 @@ impl Base64Decode<Vec<u8>> for ByteString {
         BASE64_STANDARD.decode(&self.0)
     }
 }
+
+impl Base64Decode for &str {
+    fn decode(self) -> Result<String, base64::DecodeError> {
+        let decoded = BASE64_STANDARD.decode(self)?;
+        Ok(String::from_utf8_lossy(&decoded).to_string())
+    }
+}
@@ -6,6 +6,7 @@ use kube::discovery;
 use k8s::ContainerGetExt;
 use k8s::ContainerStatusGetExt;
 use k8s::NamespaceGetExt;
+use k8s::PodConditionGetExt;
 use k8s::PodGetExt;
 
 use k8s::corev1;
@@ -16,11 +17,18 @@ pub use apiresource::APIResourceListExt;
 pub use b64::Base64Decode;
 pub use b64::Base64Encode;
 pub use namespace::NamespaceGetExt2;
+pub use node::NodeGetExt2;
 pub use pod::PodGetExt2;
 pub use service::ServiceGetExt2;
+pub use table::Table;
+pub use table::TableColumnDefinition;
+pub use table::TableRow;
+pub use table::TableRowCondition;
 
 mod apiresource;
 mod b64;
 mod namespace;
+mod node;
 mod pod;
 mod service;
+mod table;
@@ -0,0 +1,74 @@
+use super::*;
+
+pub trait NodeGetExt2 {
+    const NODE_ROLE_PREFIX: &str = "node-role.kubernetes.io/";
+    const NODE_ROLE_LABEL: &str = "kubernetes.io/role";
+
+    fn kubectl_status(&self) -> String;
+    fn roles(&self) -> Vec<&str>;
+    fn address(&self, r#type: &str) -> Option<&str>;
+    fn node_info(&self) -> Option<&corev1::NodeSystemInfo>;
+}
+
+impl NodeGetExt2 for corev1::Node {
+    // mimics `kubectl get node` `STATUS`
+    fn kubectl_status(&self) -> String {
+        let ready = self
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .find(|condition| condition.type_ == "Ready")
+            .map_or("Unknown", |condition| {
+                if condition.status == "True" {
+                    "Ready"
+                } else {
+                    "NotReady"
+                }
+            });
+        let unschedulable = self
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.unschedulable)
+            .unwrap_or_default();
+        if unschedulable {
+            format!("{ready},SchedulingDisabled")
+        } else {
+            ready.to_string()
+        }
+    }
+
+    fn roles(&self) -> Vec<&str> {
+        let labels = self.metadata.labels.iter().flatten();
+        let mut roles = labels
+            .filter_map(|(key, value)| {
+                if let Some(role) = key.strip_prefix(Self::NODE_ROLE_PREFIX) {
+                    Some(role)
+                } else if key == Self::NODE_ROLE_LABEL {
+                    Some(value.as_str())
+                } else {
+                    None
+                }
+            })
+            .filter(|role| !role.is_empty())
+            .collect::<Vec<_>>();
+        roles.sort_unstable();
+        roles.dedup();
+        roles
+    }
+
+    fn address(&self, r#type: &str) -> Option<&str> {
+        self.status
+            .as_ref()?
+            .addresses
+            .as_deref()?
+            .iter()
+            .find(|address| address.type_ == r#type)
+            .map(|address| address.address.as_str())
+    }
+
+    fn node_info(&self) -> Option<&corev1::NodeSystemInfo> {
+        self.status.as_ref()?.node_info.as_ref()
+    }
+}
@@ -6,8 +6,13 @@ pub trait PodGetExt2 {
     fn ready_containers(&self) -> usize;
     fn total_containers(&self) -> usize;
     fn restarts(&self) -> i32;
+    fn last_restart(&self) -> Option<&metav1::Time>;
     fn kubectl_status(&self) -> String;
     fn init_container_kubectl_status(&self) -> Option<String>;
+    fn ip(&self) -> Option<&str>;
+    fn node(&self) -> Option<&str>;
+    fn nominated_node(&self) -> Option<&str>;
+    fn readiness_gates_kubectl_status(&self) -> Option<String>;
 }
 
 impl PodGetExt2 for corev1::Pod {
@@ -37,6 +42,23 @@ impl PodGetExt2 for corev1::Pod {
             .sum()
     }
 
+    /// When the most recently restarted container last terminated
+    fn last_restart(&self) -> Option<&metav1::Time> {
+        self.container_statuses()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|status| {
+                status
+                    .last_state
+                    .as_ref()?
+                    .terminated
+                    .as_ref()?
+                    .finished_at
+                    .as_ref()
+            })
+            .max_by_key(|time| time.0)
+    }
+
     // mimics `kubectl get pod` `STATUS`
     fn kubectl_status(&self) -> String {
         let reason = self.phase().unwrap_or_default();
@@ -80,4 +102,33 @@ impl PodGetExt2 for corev1::Pod {
             // Show the last one
             .next_back()
     }
+
+    fn ip(&self) -> Option<&str> {
+        self.pod_ips()
+            .unwrap_or_default()
+            .first()
+            .map(|ip| ip.ip.as_str())
+            .or_else(|| self.pod_ip())
+    }
+
+    fn node(&self) -> Option<&str> {
+        self.spec()?.node_name.as_deref()
+    }
+
+    fn nominated_node(&self) -> Option<&str> {
+        self.nominated_node_name()
+    }
+
+    // mimics `kubectl get pod -o wide` `READINESS GATES`
+    fn readiness_gates_kubectl_status(&self) -> Option<String> {
+        let gates = self.readiness_gates().filter(|gates| !gates.is_empty())?;
+        let ready = gates
+            .iter()
+            .filter(|gate| {
+                self.condition(&gate.condition_type)
+                    .is_some_and(|condition| condition.is_true())
+            })
+            .count();
+        Some(format!("{ready}/{}", gates.len()))
+    }
 }
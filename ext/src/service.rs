@@ -11,6 +11,65 @@ pub trait ServiceGetExt2 {
             .as_ref()?
             .first()
     }
+
+    fn kubectl_type(&self) -> &str {
+        self.spec()
+            .and_then(|spec| spec.type_.as_deref())
+            .unwrap_or("ClusterIP")
+    }
+
+    // mimics `kubectl get service` `EXTERNAL-IP`
+    fn kubectl_external_ip(&self) -> String {
+        let external_ips = self
+            .spec()
+            .and_then(|spec| spec.external_ips.as_deref())
+            .unwrap_or_default();
+        match self.kubectl_type() {
+            "ClusterIP" | "NodePort" if external_ips.is_empty() => "<none>".to_string(),
+            "ClusterIP" | "NodePort" => external_ips.join(","),
+            "LoadBalancer" => {
+                let ingress = self
+                    .status()
+                    .and_then(|status| status.load_balancer.as_ref())
+                    .and_then(|lb| lb.ingress.as_deref())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|ingress| ingress.ip.as_ref().or(ingress.hostname.as_ref()));
+                let ips = ingress.chain(external_ips).cloned().collect::<Vec<_>>();
+                if ips.is_empty() {
+                    "<pending>".to_string()
+                } else {
+                    ips.join(",")
+                }
+            }
+            "ExternalName" => self
+                .spec()
+                .and_then(|spec| spec.external_name.clone())
+                .unwrap_or_default(),
+            _ => "<unknown>".to_string(),
+        }
+    }
+
+    // mimics `kubectl get service` `PORT(S)`
+    fn kubectl_ports(&self) -> Option<String> {
+        let ports = self
+            .spec()?
+            .ports
+            .as_deref()
+            .filter(|ports| !ports.is_empty())?
+            .iter()
+            .map(|port| {
+                let protocol = port.protocol.as_deref().unwrap_or("TCP");
+                match port.node_port {
+                    Some(node_port) if node_port != 0 => {
+                        format!("{}:{node_port}/{protocol}", port.port)
+                    }
+                    _ => format!("{}/{protocol}", port.port),
+                }
+            })
+            .collect::<Vec<_>>();
+        Some(ports.join(","))
+    }
 }
 
 impl ServiceGetExt2 for corev1::Service {
@@ -0,0 +1,94 @@
+use super::*;
+
+/// `meta.k8s.io/v1` `Table` as returned by the API server for `as=Table` requests
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Table {
+    #[serde(flatten, default)]
+    pub types: Option<kube::core::TypeMeta>,
+
+    #[serde(default)]
+    pub metadata: metav1::ListMeta,
+
+    #[serde(default)]
+    pub column_definitions: Vec<TableColumnDefinition>,
+
+    #[serde(default)]
+    pub rows: Vec<TableRow>,
+
+    /// Kind of the objects the rows describe. Not part of the API response.
+    #[serde(skip)]
+    pub kind: Option<String>,
+}
+
+impl Table {
+    pub fn with_kind(self, kind: impl ToString) -> Self {
+        let kind = Some(kind.to_string());
+        Self { kind, ..self }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableColumnDefinition {
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    #[serde(default)]
+    pub format: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    #[serde(default)]
+    pub priority: i32,
+}
+
+impl TableColumnDefinition {
+    pub fn is_name(&self) -> bool {
+        self.format == "name"
+    }
+
+    pub fn is_date(&self) -> bool {
+        self.type_ == "date"
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRow {
+    #[serde(default)]
+    pub cells: Vec<serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<TableRowCondition>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object: Option<serde_json::Value>,
+}
+
+impl TableRow {
+    pub fn namespace(&self) -> Option<&str> {
+        self.object
+            .as_ref()?
+            .pointer("/metadata/namespace")?
+            .as_str()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRowCondition {
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    pub status: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
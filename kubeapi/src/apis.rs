@@ -1,5 +1,9 @@
 use super::*;
 
+use aggregated::APIGroupDiscoveryList;
+
+mod aggregated;
+
 /// Common Kubernetes resources
 impl Kubeapi {
     /// `corev1::Namespace`
@@ -47,6 +51,138 @@ impl Kubeapi {
         self.cluster_api()
     }
 
+    /// `corev1::ServiceAccount`
+    pub fn serviceaccounts(&self) -> kube::Result<api::Api<corev1::ServiceAccount>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::Endpoints`
+    pub fn endpoints(&self) -> kube::Result<api::Api<corev1::Endpoints>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::Event`
+    pub fn events(&self) -> kube::Result<api::Api<corev1::Event>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::PersistentVolume`
+    pub fn persistentvolumes(&self) -> kube::Result<api::Api<corev1::PersistentVolume>> {
+        self.cluster_api()
+    }
+
+    /// `corev1::PersistentVolumeClaim`
+    pub fn persistentvolumeclaims(&self) -> kube::Result<api::Api<corev1::PersistentVolumeClaim>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::ReplicationController`
+    pub fn replicationcontrollers(&self) -> kube::Result<api::Api<corev1::ReplicationController>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::ResourceQuota`
+    pub fn resourcequotas(&self) -> kube::Result<api::Api<corev1::ResourceQuota>> {
+        self.namespaced_api()
+    }
+
+    /// `corev1::LimitRange`
+    pub fn limitranges(&self) -> kube::Result<api::Api<corev1::LimitRange>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::Deployment`
+    pub fn deployments(&self) -> kube::Result<api::Api<appsv1::Deployment>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::ReplicaSet`
+    pub fn replicasets(&self) -> kube::Result<api::Api<appsv1::ReplicaSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::StatefulSet`
+    pub fn statefulsets(&self) -> kube::Result<api::Api<appsv1::StatefulSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::DaemonSet`
+    pub fn daemonsets(&self) -> kube::Result<api::Api<appsv1::DaemonSet>> {
+        self.namespaced_api()
+    }
+
+    /// `appsv1::ControllerRevision`
+    pub fn controllerrevisions(&self) -> kube::Result<api::Api<appsv1::ControllerRevision>> {
+        self.namespaced_api()
+    }
+
+    /// `batchv1::Job`
+    pub fn jobs(&self) -> kube::Result<api::Api<batchv1::Job>> {
+        self.namespaced_api()
+    }
+
+    /// `batchv1::CronJob`
+    pub fn cronjobs(&self) -> kube::Result<api::Api<batchv1::CronJob>> {
+        self.namespaced_api()
+    }
+
+    /// `networkingv1::Ingress`
+    pub fn ingresses(&self) -> kube::Result<api::Api<networkingv1::Ingress>> {
+        self.namespaced_api()
+    }
+
+    /// `networkingv1::IngressClass`
+    pub fn ingressclasses(&self) -> kube::Result<api::Api<networkingv1::IngressClass>> {
+        self.cluster_api()
+    }
+
+    /// `networkingv1::NetworkPolicy`
+    pub fn networkpolicies(&self) -> kube::Result<api::Api<networkingv1::NetworkPolicy>> {
+        self.namespaced_api()
+    }
+
+    /// `rbacv1::Role`
+    pub fn roles(&self) -> kube::Result<api::Api<rbacv1::Role>> {
+        self.namespaced_api()
+    }
+
+    /// `rbacv1::RoleBinding`
+    pub fn rolebindings(&self) -> kube::Result<api::Api<rbacv1::RoleBinding>> {
+        self.namespaced_api()
+    }
+
+    /// `policyv1::PodDisruptionBudget`
+    pub fn poddisruptionbudgets(&self) -> kube::Result<api::Api<policyv1::PodDisruptionBudget>> {
+        self.namespaced_api()
+    }
+
+    /// `storagev1::StorageClass`
+    pub fn storageclasses(&self) -> kube::Result<api::Api<storagev1::StorageClass>> {
+        self.cluster_api()
+    }
+
+    /// `storagev1::CSIDriver`
+    pub fn csidrivers(&self) -> kube::Result<api::Api<storagev1::CSIDriver>> {
+        self.cluster_api()
+    }
+
+    /// `storagev1::CSINode`
+    pub fn csinodes(&self) -> kube::Result<api::Api<storagev1::CSINode>> {
+        self.cluster_api()
+    }
+
+    /// `storagev1::VolumeAttachment`
+    pub fn volumeattachments(&self) -> kube::Result<api::Api<storagev1::VolumeAttachment>> {
+        self.cluster_api()
+    }
+
+    /// `autoscalingv2::HorizontalPodAutoscaler`
+    pub fn horizontalpodautoscalers(
+        &self,
+    ) -> kube::Result<api::Api<autoscalingv2::HorizontalPodAutoscaler>> {
+        self.namespaced_api()
+    }
+
     /// `authorizationv1::SelfSubjectAccessReview`
     pub fn selfsubjectaccessreviews(
         &self,
@@ -90,3 +226,30 @@ impl Kubeapi {
         Ok(api)
     }
 }
+
+/// Discovery
+impl Kubeapi {
+    /// Discover every group and resource in two requests, `/api` and `/apis`.
+    /// Returns `None` when the server does not support aggregated discovery.
+    pub(crate) async fn aggregated_discovery(
+        &self,
+    ) -> kube::Result<Option<(metav1::APIGroupList, Vec<metav1::APIResourceList>)>> {
+        let client = self.client()?;
+        let mut groups = vec![];
+        let mut resources = vec![];
+        for path in ["/api", "/apis"] {
+            let request = http::Request::get(path)
+                .header(http::header::ACCEPT, aggregated::ACCEPT)
+                .body(vec![])
+                .map_err(kube::Error::HttpError)?;
+            let text = client.request_text(request).await?;
+            let Some(list) = APIGroupDiscoveryList::parse(&text) else {
+                return Ok(None);
+            };
+            let (more_groups, more_resources) = list.into_discovery();
+            groups.extend(more_groups);
+            resources.extend(more_resources);
+        }
+        Ok(Some((metav1::APIGroupList { groups }, resources)))
+    }
+}
@@ -0,0 +1,201 @@
+//! Minimal model of `apidiscovery.k8s.io/v2`, which neither k8s-openapi nor kube provide
+
+use super::*;
+
+pub(super) const ACCEPT: &str =
+    "application/json;g=apidiscovery.k8s.io;v=v2;as=APIGroupDiscoveryList,application/json";
+
+const KIND: &str = "APIGroupDiscoveryList";
+
+#[derive(Debug, serde::Deserialize)]
+pub(super) struct APIGroupDiscoveryList {
+    kind: Option<String>,
+    #[serde(default)]
+    items: Vec<APIGroupDiscovery>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct APIGroupDiscovery {
+    #[serde(default)]
+    metadata: metav1::ObjectMeta,
+    /// In server preference order
+    #[serde(default)]
+    versions: Vec<APIVersionDiscovery>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct APIVersionDiscovery {
+    version: String,
+    #[serde(default)]
+    resources: Vec<APIResourceDiscovery>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct APIResourceDiscovery {
+    resource: String,
+    response_kind: Option<ResponseKind>,
+    scope: String,
+    #[serde(default)]
+    singular_resource: String,
+    #[serde(default)]
+    verbs: Vec<String>,
+    short_names: Option<Vec<String>>,
+    categories: Option<Vec<String>>,
+    #[serde(default)]
+    subresources: Vec<APISubresourceDiscovery>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct APISubresourceDiscovery {
+    subresource: String,
+    response_kind: Option<ResponseKind>,
+    #[serde(default)]
+    verbs: Vec<String>,
+}
+
+/// Only the kind matters, group and version are implied by the enclosing list
+#[derive(Debug, serde::Deserialize)]
+struct ResponseKind {
+    #[serde(default)]
+    kind: String,
+}
+
+impl APIGroupDiscoveryList {
+    /// Servers without aggregated discovery ignore the `Accept` header
+    /// and answer with plain `APIVersions` or `APIGroupList`
+    pub(super) fn parse(text: &str) -> Option<Self> {
+        json::from_str::<Self>(text)
+            .ok()
+            .filter(|list| list.kind.as_deref() == Some(KIND))
+    }
+
+    pub(super) fn into_discovery(self) -> (Vec<metav1::APIGroup>, Vec<metav1::APIResourceList>) {
+        let mut groups = vec![];
+        let mut resources = vec![];
+        for group in self.items {
+            let name = group.metadata.name.unwrap_or_default();
+            let versions = group
+                .versions
+                .into_iter()
+                .map(|version| {
+                    let group_version = if name.is_empty() {
+                        version.version.clone()
+                    } else {
+                        format!("{name}/{}", version.version)
+                    };
+                    let arl = metav1::APIResourceList {
+                        group_version: group_version.clone(),
+                        resources: version
+                            .resources
+                            .into_iter()
+                            .flat_map(APIResourceDiscovery::api_resources)
+                            .collect(),
+                    };
+                    resources.push(arl);
+                    metav1::GroupVersionForDiscovery {
+                        group_version,
+                        version: version.version,
+                    }
+                })
+                .collect::<Vec<_>>();
+            groups.push(metav1::APIGroup {
+                name,
+                preferred_version: versions.first().cloned(),
+                server_address_by_client_cidrs: None,
+                versions,
+            });
+        }
+        (groups, resources)
+    }
+}
+
+impl APIResourceDiscovery {
+    /// The resource itself followed by its subresources, as legacy discovery lists them
+    fn api_resources(self) -> Vec<metav1::APIResource> {
+        let namespaced = self.scope == "Namespaced";
+        let kind = self.response_kind.map(|gvk| gvk.kind).unwrap_or_default();
+        let subresources = self
+            .subresources
+            .into_iter()
+            .map(|subresource| metav1::APIResource {
+                name: format!("{}/{}", self.resource, subresource.subresource),
+                kind: subresource
+                    .response_kind
+                    .map_or_else(|| kind.clone(), |gvk| gvk.kind),
+                namespaced,
+                verbs: subresource.verbs,
+                ..default()
+            });
+        let resource = metav1::APIResource {
+            name: self.resource.clone(),
+            singular_name: self.singular_resource,
+            kind: kind.clone(),
+            namespaced,
+            verbs: self.verbs,
+            short_names: self.short_names,
+            categories: self.categories,
+            ..default()
+        };
+        iter::once(resource).chain(subresources).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APIS: &str = r#"{
+        "kind": "APIGroupDiscoveryList",
+        "apiVersion": "apidiscovery.k8s.io/v2",
+        "metadata": {},
+        "items": [{
+            "metadata": {"name": "apps"},
+            "versions": [{
+                "version": "v1",
+                "freshness": "Current",
+                "resources": [{
+                    "resource": "deployments",
+                    "responseKind": {"group": "", "version": "", "kind": "Deployment"},
+                    "scope": "Namespaced",
+                    "singularResource": "deployment",
+                    "verbs": ["get", "list"],
+                    "shortNames": ["deploy"],
+                    "categories": ["all"],
+                    "subresources": [{
+                        "subresource": "scale",
+                        "responseKind": {"group": "autoscaling", "version": "v1", "kind": "Scale"},
+                        "verbs": ["get", "patch", "update"]
+                    }]
+                }]
+            }]
+        }]
+    }"#;
+
+    #[test]
+    fn aggregated() {
+        let (groups, resources) = APIGroupDiscoveryList::parse(APIS).unwrap().into_discovery();
+        assert_eq!(groups[0].name, "apps");
+        assert_eq!(
+            groups[0].preferred_version.as_ref().unwrap().group_version,
+            "apps/v1"
+        );
+        assert_eq!(resources[0].group_version, "apps/v1");
+        let [deployments, scale] = resources[0].resources.as_slice() else {
+            panic!("expecting deployments and deployments/scale");
+        };
+        assert_eq!(deployments.name, "deployments");
+        assert_eq!(deployments.kind, "Deployment");
+        assert_eq!(deployments.singular_name, "deployment");
+        assert!(deployments.namespaced);
+        assert_eq!(scale.name, "deployments/scale");
+        assert_eq!(scale.kind, "Scale");
+    }
+
+    #[test]
+    fn legacy() {
+        let text = r#"{"kind": "APIGroupList", "apiVersion": "v1", "groups": []}"#;
+        assert!(APIGroupDiscoveryList::parse(text).is_none());
+    }
+}
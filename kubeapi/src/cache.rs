@@ -2,8 +2,10 @@ use indexmap::IndexMap;
 
 use super::*;
 
+/// Discovery information, mirrored on disk in the same layout kubectl uses
 #[derive(Clone, Debug, Default)]
 pub struct Cache {
+    path: Option<PathBuf>,
     groups: Option<metav1::APIGroupList>,
     resources: IndexMap<String, metav1::APIResourceList>,
     took: time::Duration,
@@ -11,9 +13,11 @@ pub struct Cache {
 }
 
 impl Cache {
-    pub(super) fn try_load(self, path: impl AsRef<Path>) -> Self {
+    /// Load whatever is not older than `ttl` from `path`.
+    /// Later updates are written back there.
+    pub(super) fn try_load(self, path: impl AsRef<Path>, ttl: time::Duration) -> Self {
         let start = time::Instant::now();
-        let cached_resources = CachedResources::new(path);
+        let cached_resources = CachedResources::new(&path).with_ttl(ttl);
         let groups = cached_resources.load_server_groups().ok();
         let resources = groups
             .as_ref()
@@ -21,6 +25,7 @@ impl Cache {
             .unwrap_or_default();
         let took = start.elapsed();
         Self {
+            path: Some(path.as_ref().to_path_buf()),
             groups,
             resources,
             took,
@@ -28,6 +33,33 @@ impl Cache {
         }
     }
 
+    /// Replace the cached data with the results of live discovery
+    pub(super) fn update(
+        self,
+        groups: metav1::APIGroupList,
+        resources: Vec<metav1::APIResourceList>,
+    ) -> Self {
+        if let Some(path) = &self.path {
+            let cached_resources = CachedResources::new(path);
+            if let Err(err) = cached_resources.store(&groups, &resources) {
+                warn!(%err, path = %path.display(), "Failed to write discovery cache");
+            }
+        }
+        let resources = resources
+            .into_iter()
+            .map(|arl| (arl.group_version.clone(), arl))
+            .collect();
+        Self {
+            groups: Some(groups),
+            resources,
+            ..self
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.groups.is_none() || self.resources.is_empty()
+    }
+
     pub(super) fn api_groups(&self) -> Option<metav1::APIGroupList> {
         self.groups.clone()
     }
@@ -48,6 +80,7 @@ impl Cache {
 
 struct CachedResources {
     path: PathBuf,
+    ttl: Option<time::Duration>,
 }
 
 impl CachedResources {
@@ -56,13 +89,34 @@ impl CachedResources {
 
     fn new(path: impl AsRef<Path>) -> Self {
         let path = path.as_ref().to_path_buf();
-        trace!(from = %path.display(), "Loading cached resources");
-        Self { path }
+        trace!(at = %path.display(), "Cached resources");
+        Self { path, ttl: None }
+    }
+
+    fn with_ttl(self, ttl: time::Duration) -> Self {
+        let ttl = Some(ttl);
+        Self { ttl, ..self }
     }
 
     fn load_server_groups(&self) -> io::Result<metav1::APIGroupList> {
         let path = self.path.join(Self::SERVER_GROUPS);
-        load_json(path)
+        load_json(path, self.ttl)
+    }
+
+    fn store(
+        &self,
+        groups: &metav1::APIGroupList,
+        resources: &[metav1::APIResourceList],
+    ) -> io::Result<()> {
+        for arl in resources {
+            let path = self
+                .path
+                .join(&arl.group_version)
+                .join(Self::SERVER_RESOURCES);
+            store_json(path, arl)?;
+        }
+        // Groups go last, they make the resources visible to the next load
+        store_json(self.path.join(Self::SERVER_GROUPS), groups)
     }
 
     fn load_groups_resources(
@@ -78,6 +132,9 @@ impl CachedResources {
             .collect()
     }
 
+    /// Freshness is decided once by `servergroups.json`, it is written after the resources
+    /// and is never newer than them. Group versions without resources are missing from
+    /// live discovery as well.
     fn load_server_group_version_resources(
         &self,
         version: &metav1::GroupVersionForDiscovery,
@@ -86,17 +143,115 @@ impl CachedResources {
             .path
             .join(&version.group_version)
             .join(Self::SERVER_RESOURCES);
-        load_json(path)
+        load_json(path, None)
     }
 }
 
 #[tracing::instrument(level = "trace", err)]
-fn load_json<T>(path: PathBuf) -> io::Result<T>
+fn load_json<T>(path: PathBuf, ttl: Option<time::Duration>) -> io::Result<T>
 where
     T: serde::de::DeserializeOwned,
 {
     trace!("Loading cached data");
+    if let Some(ttl) = ttl {
+        // Modification times in the future count as fresh
+        let age = fs::metadata(&path)?
+            .modified()?
+            .elapsed()
+            .unwrap_or_default();
+        if age > ttl {
+            return Err(io::Error::other(format!("expired {age:?} ago")));
+        }
+    }
     let text = fs::read_to_string(path)?;
     let data = json::from_str(&text)?;
     Ok(data)
 }
+
+#[tracing::instrument(level = "trace", skip(data), err)]
+fn store_json<T>(path: PathBuf, data: &T) -> io::Result<()>
+where
+    T: serde::Serialize,
+{
+    trace!("Storing cached data");
+    let text = json::to_string(data)?;
+    write_atomically(&path, text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups() -> metav1::APIGroupList {
+        let version = metav1::GroupVersionForDiscovery {
+            group_version: "example.com/v1".to_string(),
+            version: "v1".to_string(),
+        };
+        metav1::APIGroupList {
+            groups: vec![metav1::APIGroup {
+                name: "example.com".to_string(),
+                preferred_version: Some(version.clone()),
+                versions: vec![version],
+                ..default()
+            }],
+        }
+    }
+
+    fn resources() -> Vec<metav1::APIResourceList> {
+        vec![metav1::APIResourceList {
+            group_version: "example.com/v1".to_string(),
+            resources: vec![metav1::APIResource {
+                name: "widgets".to_string(),
+                kind: "Widget".to_string(),
+                ..default()
+            }],
+        }]
+    }
+
+    fn tempdir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("rkubectl-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = tempdir("cache-round-trip");
+        let ttl = time::Duration::from_secs(60);
+
+        let cache = Cache::default().try_load(&path, ttl);
+        assert!(cache.is_empty());
+
+        let cache = cache.update(groups(), resources());
+        assert!(!cache.is_empty());
+        assert!(path.join("example.com/v1/serverresources.json").exists());
+
+        let cache = Cache::default().try_load(&path, ttl);
+        let resources = cache.api_resources().unwrap();
+        assert_eq!(resources[0].resources[0].name, "widgets");
+
+        // Everything has expired with zero ttl
+        let cache = Cache::default().try_load(&path, time::Duration::ZERO);
+        assert!(cache.is_empty());
+
+        // Only the groups decide, so the cache never loads partially
+        let old = time::SystemTime::now() - time::Duration::from_secs(3600);
+        fs::File::options()
+            .write(true)
+            .open(path.join("example.com/v1/serverresources.json"))
+            .and_then(|file| file.set_modified(old))
+            .unwrap();
+        let cache = Cache::default().try_load(&path, ttl);
+        assert!(!cache.is_empty());
+        assert_eq!(cache.api_resources().unwrap().len(), 1);
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn memory_only() {
+        let cache = Cache::default().update(groups(), resources());
+        assert!(!cache.is_empty());
+        assert_eq!(cache.api_groups().unwrap().groups.len(), 1);
+    }
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// Lazily built `kube::Client` shared by every clone of `Kubeapi`,
+/// so that all requests go through one connection pool
+#[derive(Clone, Default)]
+pub(crate) struct SharedClient(Arc<OnceLock<kube::Client>>);
+
+impl SharedClient {
+    pub(crate) fn get_or_try_init(&self, config: &kube::Config) -> kube::Result<kube::Client> {
+        if let Some(client) = self.0.get() {
+            return Ok(client.clone());
+        }
+        let client = kube::Client::try_from(config.clone())?;
+        // Another clone may have won the race, use whatever got there first
+        Ok(self.0.get_or_init(|| client).clone())
+    }
+}
+
+impl fmt::Debug for SharedClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedClient")
+            .field("initialized", &self.0.get().is_some())
+            .finish()
+    }
+}
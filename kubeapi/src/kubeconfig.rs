@@ -1,4 +1,6 @@
-use kube::config::KubeConfigOptions;
+use kube::config::AuthInfo;
+use kube::config::Cluster;
+use kube::config::Context;
 use kube::config::Kubeconfig;
 use kube::config::KubeconfigError;
 use kube::config::NamedAuthInfo;
@@ -7,21 +9,50 @@ use kube::config::NamedContext;
 
 use super::*;
 
+pub use files::KubeconfigFiles;
+pub use files::Stanza;
+
+mod files;
+
 impl Kubeapi {
+    /// Load kubeconfig and build the client configuration from it.
+    /// Connection flags apply to the configuration only, the returned kubeconfig is as read.
     pub async fn kubeconfig(
-        options: KubeConfigOptions,
+        options: &KubeConfigOptions,
         debug: bool,
-    ) -> Result<(kube::Config, Kubeconfig), KubeconfigError> {
-        let kubeconfig = Kubeconfig::read()
-            .map(sanitize_kubeconfig)
-            .inspect(|kubeconfig| {
-                if debug {
-                    debug!(kubeconfig = kubeconfig.debug())
-                }
-            })?;
+    ) -> Result<(kube::Config, KubeconfigFiles), KubeconfigError> {
+        let kubeconfig = read_kubeconfig(options)?;
+        let effective = sanitize_kubeconfig(kubeconfig.merged().clone());
+        if debug {
+            debug!(kubeconfig = effective.debug())
+        }
 
-        kube::Config::from_custom_kubeconfig(kubeconfig.clone(), &options)
+        let config_options = options.kube_config_options();
+        let effective = with_overrides(effective, options);
+        let impersonate_uid = options
+            .user
+            .as_deref()
+            .or_else(|| {
+                options
+                    .context
+                    .as_deref()
+                    .or(effective.current_context.as_deref())
+                    .and_then(|name| effective.get_context(name))?
+                    .context
+                    .as_ref()?
+                    .user
+                    .as_deref()
+            })
+            .and_then(|user| kubeconfig.impersonate_uid(user))
+            .map(str::to_string);
+        kube::Config::from_custom_kubeconfig(effective, &config_options)
             .await
+            .map(|mut config| {
+                if let Some(uid) = &impersonate_uid {
+                    options::set_impersonate_uid(&mut config, uid);
+                }
+                config
+            })
             .inspect(|config| {
                 if debug {
                     debug!(?config)
@@ -31,8 +62,18 @@ impl Kubeapi {
             .map(|config| (config, kubeconfig))
     }
 
+    /// Kubeconfig files behind this instance, for commands that modify them
+    pub fn kubeconfig_files(&self) -> &KubeconfigFiles {
+        &self.kubeconfig
+    }
+
+    /// `--kubeconfig`, `--context`, `--cluster`, `--user` and the connection flags as given
+    pub fn kubeconfig_options(&self) -> &KubeConfigOptions {
+        &self.kubeconfig_options
+    }
+
     pub fn current_context(&self) -> Option<&str> {
-        self.kubeconfig.current_context.as_deref()
+        self.kubeconfig.current_context()
     }
 
     pub fn get_clusters(&self) -> &[kube::config::NamedCluster] {
@@ -48,22 +89,105 @@ impl Kubeapi {
     }
 
     pub fn view(&self) -> yaml::Result<String> {
-        yaml::to_string(&self.kubeconfig)
+        yaml::to_string(self.kubeconfig.merged())
     }
 
     fn clusters(&self) -> &[NamedCluster] {
-        &self.kubeconfig.clusters
+        &self.kubeconfig.merged().clusters
     }
 
     fn contexts(&self) -> &[NamedContext] {
-        &self.kubeconfig.contexts
+        &self.kubeconfig.merged().contexts
     }
 
     fn authinfo(&self) -> &[NamedAuthInfo] {
-        &self.kubeconfig.auth_infos
+        &self.kubeconfig.merged().auth_infos
     }
 }
 
+fn read_kubeconfig(options: &KubeConfigOptions) -> Result<KubeconfigFiles, KubeconfigError> {
+    match KubeconfigFiles::load_existing(options.kubeconfig.as_deref()) {
+        // Having no kubeconfig at all is fine when the flags say where the server is
+        Err(KubeconfigError::FindPath) if options.server.is_some() => Ok(default()),
+        Err(KubeconfigError::ReadConfig(err, _))
+            if err.kind() == io::ErrorKind::NotFound && options.server.is_some() =>
+        {
+            Ok(default())
+        }
+        other => other,
+    }
+}
+
+/// Apply the connection flags to the cluster and user picked by the context,
+/// making up a context when there is none to pick
+fn with_overrides(mut kubeconfig: Kubeconfig, options: &KubeConfigOptions) -> Kubeconfig {
+    const NAME: &str = "rkubectl";
+
+    if !options.has_overrides() {
+        return kubeconfig;
+    }
+
+    let context = options
+        .context
+        .as_deref()
+        .or(kubeconfig.current_context.as_deref())
+        .and_then(|name| kubeconfig.get_context(name))
+        .and_then(|context| context.context.clone());
+    let context = match context {
+        Some(context) => context,
+        None if options.context.is_none() => {
+            let context = Context {
+                cluster: NAME.to_string(),
+                user: Some(NAME.to_string()),
+                ..default()
+            };
+            kubeconfig.contexts.push(NamedContext {
+                name: NAME.to_string(),
+                context: Some(context.clone()),
+            });
+            kubeconfig.current_context = Some(NAME.to_string());
+            kubeconfig.auth_info_mut(NAME);
+            context
+        }
+        // Let loading report the missing context
+        None => return kubeconfig,
+    };
+
+    let cluster = options.cluster.as_deref().unwrap_or(&context.cluster);
+    let cluster = kubeconfig.cluster_mut(cluster);
+    if let Some(server) = &options.server {
+        cluster.server = Some(server.clone());
+    }
+    if let Some(certificate_authority) = &options.certificate_authority {
+        cluster.certificate_authority = Some(certificate_authority.display().to_string());
+        cluster.certificate_authority_data = None;
+    }
+    if let Some(tls_server_name) = &options.tls_server_name {
+        cluster.tls_server_name = Some(tls_server_name.clone());
+    }
+    if options.insecure_skip_tls_verify {
+        cluster.insecure_skip_tls_verify = Some(true);
+        cluster.certificate_authority = None;
+        cluster.certificate_authority_data = None;
+    }
+
+    if options.token.is_some() || options.token_file.is_some() {
+        let user = options
+            .user
+            .as_deref()
+            .or(context.user.as_deref())
+            .unwrap_or(NAME);
+        let auth_info = kubeconfig.auth_info_mut(user);
+        auth_info.token = options.token.clone().map(Into::into);
+        auth_info.token_file = options
+            .token_file
+            .as_ref()
+            .map(|path| path.display().to_string());
+    }
+
+    kubeconfig
+}
+
 fn sanitize_kubeconfig(mut kubeconfig: Kubeconfig) -> Kubeconfig {
     if kubeconfig.current_context().is_none() {
         let context = kubeconfig.contexts.first().map(|ctx| ctx.name.clone());
@@ -80,6 +204,8 @@ trait KubeconfigExt {
     fn debug(&self) -> String;
     fn get_context(&self, context: &str) -> Option<&NamedContext>;
     fn current_context(&self) -> Option<&NamedContext>;
+    fn cluster_mut(&mut self, name: &str) -> &mut Cluster;
+    fn auth_info_mut(&mut self, name: &str) -> &mut AuthInfo;
 }
 
 impl KubeconfigExt for Kubeconfig {
@@ -95,4 +221,107 @@ impl KubeconfigExt for Kubeconfig {
         let context = self.current_context.as_deref()?;
         self.get_context(context)
     }
+
+    fn cluster_mut(&mut self, name: &str) -> &mut Cluster {
+        let index = self
+            .clusters
+            .iter()
+            .position(|cluster| cluster.name == name)
+            .unwrap_or_else(|| {
+                self.clusters.push(NamedCluster {
+                    name: name.to_string(),
+                    cluster: None,
+                });
+                self.clusters.len() - 1
+            });
+        self.clusters[index].cluster.get_or_insert_default()
+    }
+
+    fn auth_info_mut(&mut self, name: &str) -> &mut AuthInfo {
+        let index = self
+            .auth_infos
+            .iter()
+            .position(|auth_info| auth_info.name == name)
+            .unwrap_or_else(|| {
+                self.auth_infos.push(NamedAuthInfo {
+                    name: name.to_string(),
+                    auth_info: None,
+                });
+                self.auth_infos.len() - 1
+            });
+        self.auth_infos[index].auth_info.get_or_insert_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: dev
+contexts:
+- name: dev
+  context:
+    cluster: dev-cluster
+    user: dev-user
+clusters:
+- name: dev-cluster
+  cluster:
+    server: https://dev.example.com:6443
+    certificate-authority-data: Zm9v
+users:
+- name: dev-user
+  user:
+    token: old-token
+"#;
+
+    #[test]
+    fn no_overrides() {
+        let kubeconfig = Kubeconfig::from_yaml(KUBECONFIG).unwrap();
+        let options = KubeConfigOptions::default();
+        let kubeconfig = with_overrides(kubeconfig, &options);
+        assert_eq!(kubeconfig.clusters.len(), 1);
+        assert_eq!(kubeconfig.auth_infos.len(), 1);
+    }
+
+    #[test]
+    fn overrides() {
+        let kubeconfig = Kubeconfig::from_yaml(KUBECONFIG).unwrap();
+        let options = KubeConfigOptions {
+            server: Some("https://127.0.0.1:6443".to_string()),
+            token_file: Some(PathBuf::from("/var/run/token")),
+            tls_server_name: Some("kubernetes".to_string()),
+            insecure_skip_tls_verify: true,
+            ..default()
+        };
+        let kubeconfig = with_overrides(kubeconfig, &options);
+
+        let cluster = kubeconfig.clusters[0].cluster.as_ref().unwrap();
+        assert_eq!(cluster.server.as_deref(), Some("https://127.0.0.1:6443"));
+        assert_eq!(cluster.tls_server_name.as_deref(), Some("kubernetes"));
+        assert_eq!(cluster.insecure_skip_tls_verify, Some(true));
+        assert!(cluster.certificate_authority_data.is_none());
+
+        let auth_info = kubeconfig.auth_infos[0].auth_info.as_ref().unwrap();
+        assert!(auth_info.token.is_none());
+        assert_eq!(auth_info.token_file.as_deref(), Some("/var/run/token"));
+    }
+
+    #[test]
+    fn without_kubeconfig() {
+        let options = KubeConfigOptions {
+            server: Some("https://127.0.0.1:6443".to_string()),
+            token: Some("secret".to_string()),
+            ..default()
+        };
+        let kubeconfig = with_overrides(Kubeconfig::default(), &options);
+        assert_eq!(kubeconfig.current_context.as_deref(), Some("rkubectl"));
+        assert_eq!(kubeconfig.contexts[0].name, "rkubectl");
+        assert_eq!(kubeconfig.clusters[0].name, "rkubectl");
+        assert_eq!(kubeconfig.auth_infos[0].name, "rkubectl");
+        let auth_info = kubeconfig.auth_infos[0].auth_info.as_ref().unwrap();
+        assert!(auth_info.token.is_some());
+    }
 }
@@ -0,0 +1,801 @@
+//! Kubeconfig spread over the files listed in `KUBECONFIG`
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::*;
+
+/// Kubeconfig loaded from one or more files, remembering which file defines what.
+/// Reads see the merged view, modifications go back to the file that defines the stanza.
+#[derive(Clone, Debug, Default)]
+pub struct KubeconfigFiles {
+    files: Vec<KubeconfigFile>,
+    merged: Kubeconfig,
+}
+
+#[derive(Clone, Debug)]
+struct KubeconfigFile {
+    path: PathBuf,
+    exists: bool,
+    modified: bool,
+    /// Kept as plain YAML so that fields unknown to `Kubeconfig` survive a write.
+    /// Comments do not, `serde_yaml` has no way to keep them.
+    document: yaml::Mapping,
+}
+
+/// Named entry of one of the kubeconfig lists
+pub trait Stanza: Clone + Serialize + DeserializeOwned {
+    /// Top level key of the list in the file
+    const KEY: &'static str;
+
+    fn name(&self) -> &str;
+
+    fn stanzas(kubeconfig: &Kubeconfig) -> &[Self];
+}
+
+impl Stanza for NamedCluster {
+    const KEY: &'static str = "clusters";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn stanzas(kubeconfig: &Kubeconfig) -> &[Self] {
+        &kubeconfig.clusters
+    }
+}
+
+impl Stanza for NamedContext {
+    const KEY: &'static str = "contexts";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn stanzas(kubeconfig: &Kubeconfig) -> &[Self] {
+        &kubeconfig.contexts
+    }
+}
+
+impl Stanza for NamedAuthInfo {
+    const KEY: &'static str = "users";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn stanzas(kubeconfig: &Kubeconfig) -> &[Self] {
+        &kubeconfig.auth_infos
+    }
+}
+
+const CURRENT_CONTEXT: &str = "current-context";
+
+impl KubeconfigFiles {
+    /// Load the explicitly given file, or the files from `KUBECONFIG`, or `~/.kube/config`.
+    /// Missing files are treated as empty, they get created when written to.
+    pub fn load(path: Option<&Path>) -> Result<Self, KubeconfigError> {
+        let paths = match path {
+            Some(path) => vec![path.to_path_buf()],
+            None => env_paths()
+                .filter(|paths| !paths.is_empty())
+                .or_else(|| default_path().map(|path| vec![path]))
+                .ok_or(KubeconfigError::FindPath)?,
+        };
+        Self::from_paths(paths)
+    }
+
+    /// Like [`KubeconfigFiles::load`], but the explicitly given file has to exist
+    pub fn load_existing(path: Option<&Path>) -> Result<Self, KubeconfigError> {
+        if let Some(path) = path {
+            fs::metadata(path)
+                .map_err(|err| KubeconfigError::ReadConfig(err, path.to_path_buf()))?;
+        }
+        Self::load(path)
+    }
+
+    pub(crate) fn from_paths(
+        paths: impl IntoIterator<Item = PathBuf>,
+    ) -> Result<Self, KubeconfigError> {
+        let mut files = Vec::<KubeconfigFile>::new();
+        for path in paths {
+            // Listing the same file twice does not make it define anything twice
+            if files.iter().all(|file| file.path != path) {
+                files.push(KubeconfigFile::read(path)?);
+            }
+        }
+        let mut kubeconfig = Self {
+            files,
+            merged: default(),
+        };
+        kubeconfig.merge()?;
+        Ok(kubeconfig)
+    }
+
+    /// All the files in merge order
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|file| file.path.as_path())
+    }
+
+    /// Merged view, the first file to define a stanza or a value wins
+    pub fn merged(&self) -> &Kubeconfig {
+        &self.merged
+    }
+
+    pub fn current_context(&self) -> Option<&str> {
+        self.merged.current_context.as_deref()
+    }
+
+    /// Stanza as written in the file that defines it
+    pub fn get<S: Stanza>(&self, name: &str) -> Option<S> {
+        let index = self.origin_index::<S>(name)?;
+        self.files[index]
+            .entry(S::KEY, name)
+            .and_then(|entry| yaml::from_value(entry.clone()).ok())
+    }
+
+    /// `as-uid` of the user, `Kubeconfig` does not model it
+    pub fn impersonate_uid(&self, user: &str) -> Option<&str> {
+        let index = self.origin_index::<NamedAuthInfo>(user)?;
+        self.files[index]
+            .entry(NamedAuthInfo::KEY, user)?
+            .get("user")?
+            .get("as-uid")?
+            .as_str()
+    }
+
+    /// The file that defines the stanza
+    pub fn origin<S: Stanza>(&self, name: &str) -> Option<&Path> {
+        self.origin_index::<S>(name)
+            .map(|index| self.files[index].path.as_path())
+    }
+
+    /// Set `current-context` in the file that sets it already, or in the default file
+    pub fn set_current_context(&mut self, name: Option<&str>) -> Result<(), KubeconfigError> {
+        match name {
+            Some(name) => {
+                let index = self
+                    .files
+                    .iter()
+                    .position(|file| file.current_context().is_some())
+                    .unwrap_or_else(|| self.target());
+                if let Some(file) = self.files.get_mut(index) {
+                    file.set(CURRENT_CONTEXT, name.into());
+                }
+            }
+            None => self
+                .files
+                .iter_mut()
+                .filter(|file| file.current_context().is_some())
+                .for_each(|file| file.unset(CURRENT_CONTEXT)),
+        }
+        self.merge()
+    }
+
+    /// Replace the stanza in the file that defines it, or add it to the default file.
+    /// Fields `Kubeconfig` does not model are kept.
+    pub fn upsert<S: Stanza>(&mut self, stanza: S) -> Result<(), KubeconfigError> {
+        let index = self
+            .origin_index::<S>(stanza.name())
+            .unwrap_or_else(|| self.target());
+        let known = self
+            .get::<S>(stanza.name())
+            .map(|previous| yaml::to_value(previous).map_err(KubeconfigError::InvalidStructure))
+            .transpose()?
+            .unwrap_or_default();
+        let value = yaml::to_value(&stanza).map_err(KubeconfigError::InvalidStructure)?;
+        if let Some(file) = self.files.get_mut(index) {
+            file.upsert(S::KEY, stanza.name(), &known, value);
+        }
+        self.merge()
+    }
+
+    /// Remove the stanza from every file that defines it, so that it does not resurface.
+    /// Returns whether anything was removed.
+    pub fn remove<S: Stanza>(&mut self, name: &str) -> Result<bool, KubeconfigError> {
+        let mut removed = false;
+        for file in &mut self.files {
+            removed |= file.remove(S::KEY, name);
+        }
+        self.merge()?;
+        Ok(removed)
+    }
+
+    /// Rename the stanza in the file that defines it.
+    /// Returns whether the stanza was found.
+    pub fn rename<S: Stanza>(&mut self, from: &str, to: &str) -> Result<bool, KubeconfigError> {
+        let Some(index) = self.origin_index::<S>(from) else {
+            return Ok(false);
+        };
+        self.files[index].rename(S::KEY, from, to);
+        self.merge()?;
+        Ok(true)
+    }
+
+    /// Write every modified file back
+    pub fn save(&mut self) -> io::Result<()> {
+        for file in self.files.iter_mut().filter(|file| file.modified) {
+            file.write()?;
+        }
+        Ok(())
+    }
+
+    fn origin_index<S: Stanza>(&self, name: &str) -> Option<usize> {
+        self.files
+            .iter()
+            .position(|file| file.entry(S::KEY, name).is_some())
+    }
+
+    /// Where new stanzas go, the first file that exists or the last one listed
+    fn target(&self) -> usize {
+        self.files
+            .iter()
+            .position(|file| file.exists)
+            .unwrap_or(self.files.len().saturating_sub(1))
+    }
+
+    fn merge(&mut self) -> Result<(), KubeconfigError> {
+        self.merged = self
+            .files
+            .iter()
+            .map(KubeconfigFile::kubeconfig)
+            .try_fold(Kubeconfig::default(), |merged, kubeconfig| {
+                merged.merge(kubeconfig?)
+            })?;
+        Ok(())
+    }
+}
+
+impl KubeconfigFile {
+    fn read(path: PathBuf) -> Result<Self, KubeconfigError> {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => Some(text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(KubeconfigError::ReadConfig(err, path)),
+        };
+        let exists = text.is_some();
+        let document = text
+            .as_deref()
+            .map(document)
+            .transpose()?
+            .unwrap_or_default();
+        Ok(Self {
+            path,
+            exists,
+            modified: false,
+            document,
+        })
+    }
+
+    fn write(&mut self) -> io::Result<()> {
+        let mut document = yaml::Mapping::new();
+        // Keep files readable by other tools, which expect these up front
+        document.insert("apiVersion".into(), "v1".into());
+        document.insert("kind".into(), "Config".into());
+        document.extend(self.document.clone());
+        let text = yaml::to_string(&document).map_err(io::Error::other)?;
+        write_atomically(&self.path, text.as_bytes())?;
+        self.exists = true;
+        self.modified = false;
+        Ok(())
+    }
+
+    /// Typed view with relative paths resolved against the file location
+    fn kubeconfig(&self) -> Result<Kubeconfig, KubeconfigError> {
+        let value = yaml::Value::Mapping(self.document.clone());
+        let mut kubeconfig =
+            yaml::from_value::<Kubeconfig>(value).map_err(KubeconfigError::InvalidStructure)?;
+        if let Some(dir) = self.path.parent() {
+            for cluster in kubeconfig
+                .clusters
+                .iter_mut()
+                .filter_map(|c| c.cluster.as_mut())
+            {
+                absolute(dir, &mut cluster.certificate_authority);
+            }
+            for auth_info in kubeconfig
+                .auth_infos
+                .iter_mut()
+                .filter_map(|a| a.auth_info.as_mut())
+            {
+                absolute(dir, &mut auth_info.client_certificate);
+                absolute(dir, &mut auth_info.client_key);
+                absolute(dir, &mut auth_info.token_file);
+            }
+        }
+        Ok(kubeconfig)
+    }
+
+    fn current_context(&self) -> Option<&str> {
+        self.document
+            .get(CURRENT_CONTEXT)
+            .and_then(yaml::Value::as_str)
+            .filter(|name| !name.is_empty())
+    }
+
+    fn entries(&self, key: &str) -> &[yaml::Value] {
+        self.document
+            .get(key)
+            .and_then(yaml::Value::as_sequence)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    fn entries_mut(&mut self, key: &str) -> &mut yaml::Sequence {
+        let entries = self
+            .document
+            .entry(key.into())
+            .or_insert_with(|| yaml::Value::Sequence(default()));
+        if !entries.is_sequence() {
+            *entries = yaml::Value::Sequence(default());
+        }
+        entries.as_sequence_mut().expect("just made a sequence")
+    }
+
+    fn entry(&self, key: &str, name: &str) -> Option<&yaml::Value> {
+        self.entries(key).iter().find(|entry| is_named(entry, name))
+    }
+
+    fn set(&mut self, key: &str, value: yaml::Value) {
+        self.document.insert(key.into(), value);
+        self.modified = true;
+    }
+
+    fn unset(&mut self, key: &str) {
+        self.modified |= self.document.remove(key).is_some();
+    }
+
+    /// Merges the typed `value` into the entry, `known` is the typed view of the entry
+    /// before the change
+    fn upsert(&mut self, key: &str, name: &str, known: &yaml::Value, value: yaml::Value) {
+        let entries = self.entries_mut(key);
+        match (
+            entries.iter_mut().find(|entry| is_named(entry, name)),
+            value,
+        ) {
+            (Some(yaml::Value::Mapping(entry)), yaml::Value::Mapping(value)) => {
+                let known = known.as_mapping().cloned().unwrap_or_default();
+                merge(entry, &known, value);
+            }
+            (_, value) => entries.push(value),
+        }
+        self.modified = true;
+    }
+
+    fn remove(&mut self, key: &str, name: &str) -> bool {
+        let count = self.entries(key).len();
+        if count == 0 {
+            return false;
+        }
+        let entries = self.entries_mut(key);
+        entries.retain(|entry| !is_named(entry, name));
+        let removed = entries.len() != count;
+        self.modified |= removed;
+        removed
+    }
+
+    fn rename(&mut self, key: &str, from: &str, to: &str) {
+        let entry = self
+            .entries_mut(key)
+            .iter_mut()
+            .find(|entry| is_named(entry, from));
+        if let Some(yaml::Value::Mapping(entry)) = entry {
+            entry.insert("name".into(), to.into());
+            self.modified = true;
+        }
+    }
+}
+
+/// Keys `known` has and `value` lacks were cleared, keys `known` lacks are unknown
+/// to `Kubeconfig` and stay as written
+fn merge(entry: &mut yaml::Mapping, known: &yaml::Mapping, value: yaml::Mapping) {
+    entry.retain(|key, _| !known.contains_key(key) || value.contains_key(key));
+    for (key, value) in value {
+        match (entry.get_mut(&key), known.get(&key), value) {
+            (
+                Some(yaml::Value::Mapping(entry)),
+                Some(yaml::Value::Mapping(known)),
+                yaml::Value::Mapping(value),
+            ) => merge(entry, known, value),
+            (Some(slot), _, value) => *slot = value,
+            (None, _, value) => {
+                entry.insert(key, value);
+            }
+        }
+    }
+}
+
+fn is_named(entry: &yaml::Value, name: &str) -> bool {
+    entry.get("name").and_then(yaml::Value::as_str) == Some(name)
+}
+
+/// Parse a file into a single document.
+/// Multi document files are rare, they lose their layout by being merged into one.
+fn document(text: &str) -> Result<yaml::Mapping, KubeconfigError> {
+    let mut documents = yaml::Deserializer::from_str(text)
+        .map(yaml::Value::deserialize)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(KubeconfigError::Parse)?;
+    let value = match documents.len() {
+        0 => return Ok(default()),
+        1 => documents.remove(0),
+        _ => Kubeconfig::from_yaml(text).and_then(|kubeconfig| {
+            yaml::to_value(kubeconfig).map_err(KubeconfigError::InvalidStructure)
+        })?,
+    };
+    match value {
+        yaml::Value::Mapping(document) => {
+            // Fail early on content that is not a kubeconfig at all
+            yaml::from_value::<Kubeconfig>(yaml::Value::Mapping(document.clone()))
+                .map_err(KubeconfigError::InvalidStructure)?;
+            Ok(document)
+        }
+        // An empty file
+        yaml::Value::Null => Ok(default()),
+        other => yaml::from_value::<Kubeconfig>(other)
+            .map_err(KubeconfigError::InvalidStructure)
+            .map(|_| default()),
+    }
+}
+
+fn absolute(dir: &Path, path: &mut Option<String>) {
+    let absolute = path
+        .as_deref()
+        .filter(|path| Path::new(path).is_relative())
+        .map(|relative| dir.join(relative).display().to_string());
+    if absolute.is_some() {
+        *path = absolute;
+    }
+}
+
+fn env_paths() -> Option<Vec<PathBuf>> {
+    let value = std::env::var_os("KUBECONFIG")?;
+    let paths = std::env::split_paths(&value)
+        .filter(|path| !path.as_os_str().is_empty())
+        .collect();
+    Some(paths)
+}
+
+fn default_path() -> Option<PathBuf> {
+    etcetera::home_dir()
+        .ok()
+        .map(|home| home.join(".kube").join("config"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: dev
+contexts:
+- name: dev
+  context:
+    cluster: dev
+    user: dev
+    x-ctx: keep me
+clusters:
+- name: dev
+  cluster:
+    server: https://dev.example.com:6443
+    certificate-authority: ca.crt
+    x-inner: keep me
+  x-note: keep me
+users:
+- name: dev
+  user:
+    token: dev-token
+    as-uid: "42"
+"#;
+
+    const SECOND: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: prod
+contexts:
+- name: prod
+  context:
+    cluster: prod
+    user: prod
+- name: dev
+  context:
+    cluster: shadowed
+clusters:
+- name: prod
+  cluster:
+    server: https://prod.example.com:6443
+users:
+- name: prod
+  user:
+    token: prod-token
+preferences: {}
+x-tool-settings:
+  color: true
+"#;
+
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("rkubectl-kubeconfig-{name}-{}", process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            for (file, text) in files {
+                fs::write(dir.join(file), text).unwrap();
+            }
+            Self(dir)
+        }
+
+        fn path(&self, file: &str) -> PathBuf {
+            self.0.join(file)
+        }
+
+        fn load(&self, files: &[&str]) -> KubeconfigFiles {
+            KubeconfigFiles::from_paths(files.iter().map(|file| self.path(file))).unwrap()
+        }
+
+        fn read(&self, file: &str) -> String {
+            fs::read_to_string(self.path(file)).unwrap()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn merge_order() {
+        let fixture = Fixture::new("merge", &[("first", FIRST), ("second", SECOND)]);
+        let kubeconfig = fixture.load(&["first", "second"]);
+
+        assert_eq!(kubeconfig.current_context(), Some("dev"));
+        let merged = kubeconfig.merged();
+        assert_eq!(merged.contexts.len(), 2);
+        let dev = merged.contexts.iter().find(|c| c.name == "dev").unwrap();
+        assert_eq!(dev.context.as_ref().unwrap().cluster, "dev");
+        let ca = merged.clusters[0].cluster.as_ref().unwrap();
+        assert_eq!(
+            ca.certificate_authority.as_deref(),
+            Some(fixture.path("ca.crt").display().to_string().as_str())
+        );
+
+        assert_eq!(
+            kubeconfig.origin::<NamedContext>("dev"),
+            Some(fixture.path("first").as_path())
+        );
+        assert_eq!(
+            kubeconfig.origin::<NamedCluster>("prod"),
+            Some(fixture.path("second").as_path())
+        );
+        assert_eq!(kubeconfig.origin::<NamedAuthInfo>("nobody"), None);
+
+        assert_eq!(kubeconfig.impersonate_uid("dev"), Some("42"));
+        assert_eq!(kubeconfig.impersonate_uid("prod"), None);
+    }
+
+    #[test]
+    fn write_back_to_origin() {
+        let fixture = Fixture::new("origin", &[("first", FIRST), ("second", SECOND)]);
+        let mut kubeconfig = fixture.load(&["first", "second"]);
+
+        let mut prod = kubeconfig.get::<NamedCluster>("prod").unwrap();
+        prod.cluster.as_mut().unwrap().server = Some("https://new.example.com".to_string());
+        kubeconfig.upsert(prod).unwrap();
+        kubeconfig.save().unwrap();
+
+        let first = fixture.read("first");
+        assert!(!first.contains("new.example.com"));
+        let second = fixture.read("second");
+        assert!(second.contains("https://new.example.com"));
+        // Untouched stanzas and unknown fields survive
+        assert!(second.contains("prod-token"));
+        assert!(second.contains("x-tool-settings"));
+
+        let reloaded = fixture.load(&["first", "second"]);
+        let prod = reloaded.get::<NamedCluster>("prod").unwrap();
+        assert_eq!(
+            prod.cluster.unwrap().server.as_deref(),
+            Some("https://new.example.com")
+        );
+    }
+
+    #[test]
+    fn relative_paths_stay_relative() {
+        let fixture = Fixture::new("relative", &[("first", FIRST)]);
+        let mut kubeconfig = fixture.load(&["first"]);
+
+        let mut dev = kubeconfig.get::<NamedCluster>("dev").unwrap();
+        assert_eq!(
+            dev.cluster
+                .as_ref()
+                .unwrap()
+                .certificate_authority
+                .as_deref(),
+            Some("ca.crt")
+        );
+        dev.cluster.as_mut().unwrap().tls_server_name = Some("kubernetes".to_string());
+        kubeconfig.upsert(dev).unwrap();
+
+        let mut context = kubeconfig.get::<NamedContext>("dev").unwrap();
+        context.context.as_mut().unwrap().namespace = Some("foo".to_string());
+        kubeconfig.upsert(context).unwrap();
+        kubeconfig.save().unwrap();
+
+        let first = fixture.read("first");
+        assert!(first.contains("certificate-authority: ca.crt"));
+        assert!(first.contains("tls-server-name: kubernetes"));
+        assert!(first.contains("namespace: foo"));
+        // Unknown fields survive next to `name` and inside the body
+        assert!(first.contains("x-note: keep me"));
+        assert!(first.contains("x-inner: keep me"));
+        assert!(first.contains("x-ctx: keep me"));
+
+        // While the fields `Kubeconfig` knows about can still be cleared
+        let mut kubeconfig = fixture.load(&["first"]);
+        let mut dev = kubeconfig.get::<NamedCluster>("dev").unwrap();
+        dev.cluster.as_mut().unwrap().certificate_authority = None;
+        kubeconfig.upsert(dev).unwrap();
+        kubeconfig.save().unwrap();
+
+        let first = fixture.read("first");
+        assert!(!first.contains("certificate-authority"));
+        assert!(first.contains("x-inner: keep me"));
+    }
+
+    #[test]
+    fn new_stanzas() {
+        let fixture = Fixture::new("new", &[("second", SECOND)]);
+        let mut kubeconfig = fixture.load(&["missing", "second"]);
+
+        let context = NamedContext {
+            name: "staging".to_string(),
+            context: Some(Context {
+                cluster: "prod".to_string(),
+                ..default()
+            }),
+        };
+        kubeconfig.upsert(context).unwrap();
+        kubeconfig.save().unwrap();
+
+        // The first existing file gets them
+        assert!(!fixture.path("missing").exists());
+        assert!(fixture.read("second").contains("staging"));
+
+        // And when no file exists, the last one is created
+        let mut kubeconfig = fixture.load(&["missing", "other"]);
+        kubeconfig.set_current_context(Some("staging")).unwrap();
+        kubeconfig.save().unwrap();
+        assert!(!fixture.path("missing").exists());
+        let other = fixture.read("other");
+        assert!(other.contains("kind: Config"));
+        assert!(other.contains("current-context: staging"));
+    }
+
+    #[test]
+    fn remove_and_rename() {
+        let fixture = Fixture::new("remove", &[("first", FIRST), ("second", SECOND)]);
+        let mut kubeconfig = fixture.load(&["first", "second"]);
+
+        // Removed everywhere, the shadowed `dev` must not come back
+        assert!(kubeconfig.remove::<NamedContext>("dev").unwrap());
+        assert!(!kubeconfig.remove::<NamedContext>("dev").unwrap());
+        assert!(
+            kubeconfig
+                .rename::<NamedContext>("prod", "production")
+                .unwrap()
+        );
+        assert!(
+            !kubeconfig
+                .rename::<NamedContext>("prod", "production")
+                .unwrap()
+        );
+        kubeconfig.save().unwrap();
+
+        let reloaded = fixture.load(&["first", "second"]);
+        let names = reloaded
+            .merged()
+            .contexts
+            .iter()
+            .map(|context| context.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["production"]);
+        assert_eq!(
+            reloaded.origin::<NamedContext>("production"),
+            Some(fixture.path("second").as_path())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_through_symlink() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::os::unix::fs::symlink;
+
+        let fixture = Fixture::new("symlink", &[("first", FIRST)]);
+        fs::set_permissions(fixture.path("first"), fs::Permissions::from_mode(0o600)).unwrap();
+        symlink(fixture.path("first"), fixture.path("link")).unwrap();
+
+        let mut kubeconfig = fixture.load(&["link"]);
+        kubeconfig.set_current_context(Some("prod")).unwrap();
+        kubeconfig.save().unwrap();
+
+        let link = fs::symlink_metadata(fixture.path("link")).unwrap();
+        assert!(link.file_type().is_symlink());
+        assert!(fixture.read("first").contains("current-context: prod"));
+        let mode = fs::metadata(fixture.path("first"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn new_files_are_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture = Fixture::new("private", &[]);
+        let mut kubeconfig = fixture.load(&["new"]);
+        kubeconfig.set_current_context(Some("dev")).unwrap();
+        kubeconfig.save().unwrap();
+
+        let mode = fs::metadata(fixture.path("new"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn explicit_file_must_exist() {
+        let fixture = Fixture::new("explicit", &[("first", FIRST)]);
+        assert!(KubeconfigFiles::load_existing(Some(&fixture.path("first"))).is_ok());
+        let err = KubeconfigFiles::load_existing(Some(&fixture.path("missing"))).unwrap_err();
+        assert!(matches!(err, KubeconfigError::ReadConfig(..)));
+    }
+
+    #[test]
+    fn new_explicit_file() {
+        let fixture = Fixture::new("explicit-new", &[]);
+        let path = fixture.path("new.yaml");
+        let mut kubeconfig = KubeconfigFiles::load(Some(&path)).unwrap();
+        assert!(kubeconfig.merged().clusters.is_empty());
+
+        let cluster = NamedCluster {
+            name: "foo".to_string(),
+            cluster: Some(kube::config::Cluster {
+                server: Some("https://foo.example.com".to_string()),
+                ..default()
+            }),
+        };
+        kubeconfig.upsert(cluster).unwrap();
+        kubeconfig.save().unwrap();
+
+        let reloaded = KubeconfigFiles::load_existing(Some(&path)).unwrap();
+        let foo = reloaded.get::<NamedCluster>("foo").unwrap();
+        assert_eq!(
+            foo.cluster.unwrap().server.as_deref(),
+            Some("https://foo.example.com")
+        );
+    }
+
+    #[test]
+    fn current_context() {
+        let fixture = Fixture::new("current", &[("first", FIRST), ("second", SECOND)]);
+        let mut kubeconfig = fixture.load(&["second", "first"]);
+        assert_eq!(kubeconfig.current_context(), Some("prod"));
+
+        kubeconfig.set_current_context(Some("dev")).unwrap();
+        kubeconfig.save().unwrap();
+        assert!(fixture.read("second").contains("current-context: dev"));
+        assert!(fixture.read("first").contains("current-context: dev"));
+
+        kubeconfig.set_current_context(None).unwrap();
+        assert_eq!(kubeconfig.current_context(), None);
+    }
+}
@@ -6,13 +6,19 @@ use std::collections::BTreeSet;
 use std::fmt;
 use std::fs;
 use std::io;
+use std::io::Write;
+use std::iter;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::sync::OnceLock;
 use std::time;
 
 use k8s_openapi_ext as k8s;
 use kube::api;
 use kube::discovery;
+use kube::runtime::watcher;
 use kube_client_ext::KubeClientExt;
 use serde_json as json;
 use serde_yaml as yaml;
@@ -20,25 +26,39 @@ use tracing::debug;
 use tracing::error;
 use tracing::info;
 use tracing::trace;
+use tracing::warn;
 
+use k8s::appsv1;
 use k8s::authenticationv1;
 use k8s::authorizationv1;
+use k8s::autoscalingv2;
+use k8s::batchv1;
 use k8s::corev1;
 use k8s::metav1;
+use k8s::openapi::api::networking::v1 as networkingv1;
+use k8s::policyv1;
 use k8s::rbacv1;
+use k8s::storagev1;
 
+use rkubectl_ext::Table;
 use rkubectl_features::Feature;
 
 pub use cache::Cache;
 pub use cascade::Cascade;
 pub use dryrun::DryRun;
+pub use kubeconfig::KubeconfigFiles;
+pub use kubeconfig::Stanza;
 pub use namespace::Namespace;
 pub use options::KubeConfigOptions;
 pub use options::KubeapiOptions;
+pub use selector::SelectorError;
+pub use selector::Selectors;
+pub use table::Printed;
 
 mod apis;
 mod cache;
 mod cascade;
+mod client;
 mod dryrun;
 mod features;
 mod info;
@@ -47,7 +67,9 @@ mod namespace;
 mod options;
 mod params;
 mod raw;
+mod selector;
 mod server;
+mod table;
 mod version;
 
 /// Kubeapi is a higher-level Kubernetes API client that provides additional features
@@ -55,9 +77,14 @@ mod version;
 #[derive(Clone, Debug)]
 pub struct Kubeapi {
     config: kube::Config,
-    kubeconfig: kube::config::Kubeconfig,
+    client: client::SharedClient,
+    kubeconfig: KubeconfigFiles,
+    kubeconfig_options: KubeConfigOptions,
     cache: Cache,
     namespace: Namespace,
+    selectors: Selectors,
+    server_print: bool,
+    server_print_objects: bool,
     debug: bool,
     options: KubeapiOptions,
 }
@@ -67,24 +94,28 @@ impl Kubeapi {
         config: &KubeConfigOptions,
         options: &KubeapiOptions,
         debug: bool,
-    ) -> kube::Result<Self> {
-        let config_options = config.kube_config_options();
+    ) -> Result<Self, kube::config::KubeconfigError> {
         let options = options.clone();
+        let kubeconfig_options = config.clone();
         let namespace = default();
         let cache = cache::Cache::default();
-        Self::kubeconfig(config_options, debug)
+        Self::kubeconfig(config, debug)
             .await
             .inspect_err(|err| error!(%err, "from_kubeconfig"))
             .map(|(config, kubeconfig)| Self {
-                config,
+                config: options.configure_impersonation(options.configure_timeouts(config)),
+                client: default(),
                 kubeconfig,
+                kubeconfig_options,
                 cache,
                 namespace,
+                selectors: default(),
+                server_print: false,
+                server_print_objects: false,
                 debug,
                 options,
             })
             .and_then(Self::try_load_cache)
-            .map_err(|_| kube::Error::LinesCodecMaxLineLengthExceeded)
     }
 
     pub fn cluster_url(&self) -> String {
@@ -97,9 +128,33 @@ impl Kubeapi {
         }
     }
 
-    /// Create a kube::Client from the current configuration.
+    /// The `kube::Client` for the current configuration.
+    /// It is created on first use and shared with all the clones of this instance.
     pub fn client(&self) -> kube::Result<kube::Client> {
-        kube::Client::try_from(self.config.clone())
+        self.client.get_or_try_init(&self.config)
+    }
+
+    /// The user requests are sent on behalf of, either from `--as` or from kubeconfig
+    pub fn impersonate_user(&self) -> Option<&str> {
+        self.config.auth_info.impersonate.as_deref()
+    }
+
+    /// Human readable description of the impersonated identity
+    pub fn impersonation(&self) -> Option<String> {
+        let user = self.impersonate_user()?;
+        let mut text = format!("user \"{user}\"");
+        if let Some(groups) = &self.config.auth_info.impersonate_groups {
+            text.push_str(&format!(", groups [{}]", groups.join(",")));
+        }
+        if let Some(uid) = options::impersonate_uid(&self.config) {
+            text.push_str(&format!(", uid \"{uid}\""));
+        }
+        Some(text)
+    }
+
+    /// How many requests may be sent to the server at once
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.options.max_concurrent_requests()
     }
 
     /// Returns the path to the cache file based on the current kubeconfig context.
@@ -108,8 +163,11 @@ impl Kubeapi {
     }
 
     fn try_load_cache(self) -> Result<Self, kube::config::KubeconfigError> {
+        if self.options.no_cache {
+            return Ok(self);
+        }
         let path = self.cache_path()?;
-        let cache = self.cache.try_load(path);
+        let cache = self.cache.try_load(path, self.options.cache_ttl());
         if self.debug {
             info!("Loading cache took {:?}", cache.took());
         }
@@ -127,6 +185,41 @@ impl Kubeapi {
         &self.namespace
     }
 
+    /// Set label and field selectors used for `LIST` operations.
+    /// This method returns a new instance with the updated selectors.
+    pub fn with_selectors(self, selectors: Selectors) -> Self {
+        Self { selectors, ..self }
+    }
+
+    /// Get the label and field selectors of the Kubeapi instance.
+    pub fn selectors(&self) -> &Selectors {
+        &self.selectors
+    }
+
+    /// Ask the server to render generic resources as `Table`.
+    /// This method returns a new instance with the updated mode.
+    pub fn with_server_print(self, server_print: bool) -> Self {
+        Self {
+            server_print,
+            ..self
+        }
+    }
+
+    /// Whether generic resources are rendered by the server
+    pub fn server_print(&self) -> bool {
+        self.server_print
+    }
+
+    /// Have server rendered rows carry the whole object rather than its metadata only,
+    /// so that they can be sorted by any field.
+    /// This method returns a new instance with the updated mode.
+    pub fn with_server_print_objects(self, server_print_objects: bool) -> Self {
+        Self {
+            server_print_objects,
+            ..self
+        }
+    }
+
     pub fn show_namespace(&self) -> bool {
         matches!(self.namespace, Namespace::All)
     }
@@ -224,15 +317,86 @@ impl Kubeapi {
         let config = kube::Config::new("http://localhost:6443".parse().unwrap());
         Self {
             config,
+            client: default(),
             kubeconfig: default(),
+            kubeconfig_options: default(),
             cache: default(),
             namespace: default(),
+            selectors: default(),
+            server_print: default(),
+            server_print_objects: default(),
             debug: default(),
             options: default(),
         }
     }
 }
 
+impl Kubeapi {
+    /// Create a `Kubeapi` instance that knows the kubeconfig files only.
+    /// Meant for commands that edit kubeconfig and never contact the server,
+    /// they have to work even when kubeconfig does not describe a usable cluster.
+    pub fn offline(
+        config: &KubeConfigOptions,
+        debug: bool,
+    ) -> Result<Self, kube::config::KubeconfigError> {
+        let kubeconfig = KubeconfigFiles::load(config.kubeconfig.as_deref())?;
+        Ok(Self {
+            kubeconfig,
+            kubeconfig_options: config.clone(),
+            debug,
+            ..Self::local()
+        })
+    }
+}
+
 fn default<T: Default>() -> T {
     T::default()
 }
+
+/// Write to a temporary sibling first and rename it in place, so that
+/// concurrent readers only ever see complete files.
+/// A symlink is followed and its target replaced, and the permissions of the
+/// replaced file are kept, new files are only readable by the owner.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let path = match fs::canonicalize(path) {
+        Ok(path) => path,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => path.to_path_buf(),
+        Err(err) => return Err(err),
+    };
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir)?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = dir.join(format!(".{name}.{}.tmp", process::id()));
+    let permissions = fs::metadata(&path).map(|metadata| metadata.permissions());
+    let _ = fs::remove_file(&tmp);
+    create_private(&tmp)
+        .and_then(|mut file| {
+            if let Ok(permissions) = permissions {
+                file.set_permissions(permissions)?;
+            }
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&tmp, &path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })
+}
+
+#[cfg(unix)]
+fn create_private(path: &Path) -> io::Result<fs::File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+fn create_private(path: &Path) -> io::Result<fs::File> {
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+}
@@ -8,20 +8,81 @@ pub struct KubeapiOptions {
     #[arg(long, global = true)]
     pub cache_dir: Option<PathBuf>,
 
+    /// How long cached discovery information stays valid (e.g. 6h, 30m, 1h30m)
+    #[arg(long, global = true, value_parser = duration)]
+    pub cache_ttl: Option<time::Duration>,
+
+    /// Neither read nor write the discovery cache, always ask the server
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
+    /// The length of time to wait before giving up on a single server request (e.g. 1s, 2m, 3h).
+    /// Zero means don't timeout requests.
+    #[arg(long, global = true, value_parser = duration)]
+    pub request_timeout: Option<time::Duration>,
+
+    /// Maximum number of requests sent to the server at once by commands that fan out
+    #[arg(long, global = true)]
+    pub max_concurrent_requests: Option<usize>,
+
     /// Username to impersonate for the operation. User could be a regular user or a service account in a namespace.
-    #[arg(long = "as", global = true)]
+    #[arg(long = "as", global = true, value_parser = header_value)]
     pub as_user: Option<String>,
 
     /// Group to impersonate for the operation, this flag can be repeated to specify multiple groups.
-    #[arg(long, global = true)]
+    #[arg(long, global = true, requires = "as_user", value_parser = header_value)]
     pub as_group: Option<Vec<String>>,
 
     /// UID to impersonate for the operation.
-    #[arg(long, global = true)]
+    #[arg(long, global = true, requires = "as_user", value_parser = header_value)]
     pub as_uid: Option<String>,
 }
 
 impl KubeapiOptions {
+    /// Same as kubectl
+    const DEFAULT_CACHE_TTL: time::Duration = time::Duration::from_secs(6 * 60 * 60);
+
+    const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(Self::DEFAULT_MAX_CONCURRENT_REQUESTS)
+            .max(1)
+    }
+
+    /// Apply `--request-timeout`, leaving the client defaults alone when it is not given
+    pub fn configure_timeouts(&self, config: kube::Config) -> kube::Config {
+        match self.request_timeout {
+            Some(timeout) => {
+                let timeout = Some(timeout).filter(|timeout| !timeout.is_zero());
+                kube::Config {
+                    read_timeout: timeout,
+                    write_timeout: timeout,
+                    ..config
+                }
+            }
+            None => config,
+        }
+    }
+
+    /// Apply `--as`, `--as-group` and `--as-uid` on top of whatever kubeconfig says
+    pub fn configure_impersonation(&self, mut config: kube::Config) -> kube::Config {
+        if let Some(user) = &self.as_user {
+            config.auth_info.impersonate = Some(user.clone());
+        }
+        if let Some(groups) = &self.as_group {
+            config.auth_info.impersonate_groups = Some(groups.clone());
+        }
+        if let Some(uid) = self.as_uid.as_deref() {
+            set_impersonate_uid(&mut config, uid);
+        }
+        config
+    }
+
+    pub fn cache_ttl(&self) -> time::Duration {
+        self.cache_ttl.unwrap_or(Self::DEFAULT_CACHE_TTL)
+    }
+
     pub fn cache_dir(&self) -> PathBuf {
         self.cache_dir
             .clone()
@@ -43,11 +104,110 @@ impl KubeapiOptions {
     }
 }
 
+const IMPERSONATE_UID: http::HeaderName = http::HeaderName::from_static("impersonate-uid");
+
+/// kube has no notion of impersonated UID, send the header ourselves.
+/// Replaces the UID set before, `--as-uid` wins over kubeconfig `as-uid`.
+pub(crate) fn set_impersonate_uid(config: &mut kube::Config, uid: &str) {
+    if let Ok(uid) = http::HeaderValue::from_str(uid) {
+        config.headers.retain(|(name, _)| name != IMPERSONATE_UID);
+        config.headers.push((IMPERSONATE_UID, uid));
+    }
+}
+
+pub(crate) fn impersonate_uid(config: &kube::Config) -> Option<&str> {
+    config
+        .headers
+        .iter()
+        .find(|(name, _)| name == IMPERSONATE_UID)
+        .and_then(|(_, uid)| uid.to_str().ok())
+}
+
+/// Impersonation values travel in HTTP headers, reject what cannot be sent
+fn header_value(text: &str) -> Result<String, http::header::InvalidHeaderValue> {
+    http::HeaderValue::from_str(text)?;
+    Ok(text.to_string())
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error(
+    "invalid duration '{0}', expecting a sequence of numbers with h, m, s or ms units (e.g. 1h30m)"
+)]
+struct InvalidDuration(String);
+
+/// Parses Go style durations limited to hours, minutes, seconds and milliseconds
+fn duration(text: &str) -> Result<time::Duration, InvalidDuration> {
+    let invalid = || InvalidDuration(text.to_string());
+    match text {
+        "" => return Err(invalid()),
+        "0" => return Ok(time::Duration::ZERO),
+        _ => {}
+    }
+    let mut rest = text;
+    let mut millis = 0u64;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let value = rest[..digits].parse::<u64>().map_err(|_| invalid())?;
+        rest = &rest[digits..];
+        let (unit, len) = if rest.starts_with("ms") {
+            (1, 2)
+        } else {
+            let unit = rest.chars().next().ok_or_else(invalid)?;
+            let len = unit.len_utf8();
+            match unit {
+                'h' => (60 * 60 * 1000, len),
+                'm' => (60 * 1000, len),
+                's' => (1000, len),
+                _ => return Err(invalid()),
+            }
+        };
+        millis = value
+            .checked_mul(unit)
+            .and_then(|value| millis.checked_add(value))
+            .ok_or_else(invalid)?;
+        rest = &rest[len..];
+    }
+    Ok(time::Duration::from_millis(millis))
+}
+
 /// This struct mirrors `KubeConfigOptions` from `kube::config` crate.
 /// It exists here to allow using the same struct in the CLI since
 /// `kube::config::KubeConfigOptions` does not derive `clap::Args`.
+/// It also carries kubectl's connection flags, which override the loaded kubeconfig.
 #[derive(Clone, Debug, Default, Args)]
 pub struct KubeConfigOptions {
+    /// Path to the kubeconfig file to use for CLI requests.
+    #[arg(long, global = true)]
+    pub kubeconfig: Option<PathBuf>,
+
+    /// The address and port of the Kubernetes API server
+    #[arg(short = 's', long, global = true)]
+    pub server: Option<String>,
+
+    /// Bearer token for authentication to the API server
+    #[arg(long, global = true)]
+    pub token: Option<String>,
+
+    /// Path to a file holding the bearer token for authentication to the API server
+    #[arg(long, global = true, conflicts_with = "token")]
+    pub token_file: Option<PathBuf>,
+
+    /// Path to a cert file for the certificate authority
+    #[arg(long, global = true)]
+    pub certificate_authority: Option<PathBuf>,
+
+    /// Server name to use for server certificate validation.
+    /// If it is not provided, the hostname used to contact the server is used
+    #[arg(long, global = true)]
+    pub tls_server_name: Option<String>,
+
+    /// If true, the server's certificate will not be checked for validity.
+    /// This will make your HTTPS connections insecure
+    #[arg(long, global = true, conflicts_with = "certificate_authority")]
+    pub insecure_skip_tls_verify: bool,
+
     /// The name of the kubeconfig cluster to use
     #[arg(long, global = true)]
     pub cluster: Option<String>,
@@ -69,4 +229,95 @@ impl KubeConfigOptions {
             user: self.user.clone(),
         }
     }
+
+    /// Whether any of the connection flags is given
+    pub(crate) fn has_overrides(&self) -> bool {
+        self.server.is_some()
+            || self.token.is_some()
+            || self.token_file.is_some()
+            || self.certificate_authority.is_some()
+            || self.tls_server_name.is_some()
+            || self.insecure_skip_tls_verify
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        let secs = |text| duration(text).map(|duration| duration.as_secs());
+        assert_eq!(secs("6h"), Ok(21600));
+        assert_eq!(secs("1h30m"), Ok(5400));
+        assert_eq!(secs("90s"), Ok(90));
+        assert_eq!(secs("0"), Ok(0));
+        assert!(secs("").is_err());
+        assert!(secs("10").is_err());
+        assert!(secs("5d").is_err());
+        assert!(secs("h").is_err());
+        assert!(secs("5µ").is_err());
+        assert!(secs("1é").is_err());
+        assert!(secs("1m5µs").is_err());
+        let millis = |text| duration(text).map(|duration| duration.as_millis());
+        assert_eq!(millis("500ms"), Ok(500));
+        assert_eq!(millis("1s250ms"), Ok(1250));
+        assert_eq!(millis("2m"), Ok(120_000));
+    }
+
+    #[test]
+    fn impersonation() {
+        let config = kube::Config::new("http://localhost:6443".parse().unwrap());
+        let options = KubeapiOptions {
+            as_user: Some("system:serviceaccount:default:viewer".to_string()),
+            as_group: Some(vec!["viewers".to_string()]),
+            as_uid: Some("1234".to_string()),
+            ..default()
+        };
+        let config = options.configure_impersonation(config);
+        assert_eq!(
+            config.auth_info.impersonate.as_deref(),
+            Some("system:serviceaccount:default:viewer")
+        );
+        assert_eq!(
+            config.auth_info.impersonate_groups,
+            Some(vec!["viewers".to_string()])
+        );
+        assert_eq!(config.headers[0].0, "impersonate-uid");
+        assert_eq!(config.headers[0].1, "1234");
+
+        // Set from kubeconfig first, the flag replaces it
+        let mut config = kube::Config::new("http://localhost:6443".parse().unwrap());
+        set_impersonate_uid(&mut config, "42");
+        let config = options.configure_impersonation(config);
+        assert_eq!(config.headers.len(), 1);
+        assert_eq!(impersonate_uid(&config), Some("1234"));
+
+        assert!(header_value("bad\nvalue").is_err());
+    }
+
+    #[test]
+    fn timeouts() {
+        let config = || kube::Config::new("http://localhost:6443".parse().unwrap());
+        let defaults = config();
+
+        let options = KubeapiOptions::default();
+        let configured = options.configure_timeouts(config());
+        assert_eq!(configured.read_timeout, defaults.read_timeout);
+
+        let options = KubeapiOptions {
+            request_timeout: Some(time::Duration::from_secs(5)),
+            ..default()
+        };
+        let configured = options.configure_timeouts(config());
+        assert_eq!(configured.read_timeout, Some(time::Duration::from_secs(5)));
+        assert_eq!(configured.write_timeout, Some(time::Duration::from_secs(5)));
+
+        let options = KubeapiOptions {
+            request_timeout: Some(time::Duration::ZERO),
+            ..default()
+        };
+        let configured = options.configure_timeouts(config());
+        assert_eq!(configured.read_timeout, None);
+    }
 }
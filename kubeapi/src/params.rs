@@ -7,9 +7,42 @@ impl Kubeapi {
         api::GetParams::default()
     }
 
-    /// Default parameters for `LIST` operations
+    /// Parameters for `LIST` operations, honoring label and field selectors
     pub fn list_params(&self) -> api::ListParams {
-        api::ListParams::default()
+        let Selectors {
+            selector,
+            field_selector,
+        } = &self.selectors;
+        let lp = api::ListParams::default();
+        let lp = if let Some(selector) = selector {
+            lp.labels(selector)
+        } else {
+            lp
+        };
+        if let Some(field_selector) = field_selector {
+            lp.fields(field_selector)
+        } else {
+            lp
+        }
+    }
+
+    /// Watcher configuration, honoring label and field selectors
+    pub fn watcher_config(&self) -> watcher::Config {
+        let Selectors {
+            selector,
+            field_selector,
+        } = &self.selectors;
+        let config = watcher::Config::default();
+        let config = if let Some(selector) = selector {
+            config.labels(selector)
+        } else {
+            config
+        };
+        if let Some(field_selector) = field_selector {
+            config.fields(field_selector)
+        } else {
+            config
+        }
     }
 
     /// Default parameters for `POST` operations
@@ -0,0 +1,327 @@
+use clap::Args;
+
+/// Label and field selectors applied to `LIST` operations
+#[derive(Clone, Debug, Default, Args)]
+pub struct Selectors {
+    /// Selector (label query) to filter on, supports '=', '==', '!=', 'in', 'notin'.
+    /// (e.g. -l key1=value1,key2=value2,key3 in (value3)).
+    /// Matching objects must satisfy all of the specified label constraints.
+    #[arg(short = 'l', long, value_parser = label_selector)]
+    pub selector: Option<String>,
+
+    /// Selector (field query) to filter on, supports '=', '==', and '!='.
+    /// (e.g. --field-selector key1=value1,key2=value2).
+    /// The server only supports a limited number of field queries per type.
+    #[arg(long, value_parser = field_selector)]
+    pub field_selector: Option<String>,
+}
+
+impl Selectors {
+    pub fn is_empty(&self) -> bool {
+        self.selector.is_none() && self.field_selector.is_none()
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SelectorError {
+    #[error("empty requirement in selector '{0}'")]
+    EmptyRequirement(String),
+    #[error("invalid label key '{0}'")]
+    InvalidKey(String),
+    #[error("invalid label value '{0}'")]
+    InvalidValue(String),
+    #[error("'{0}' requires an integer value, found '{1}'")]
+    NotAnInteger(&'static str, String),
+    #[error("'{0}' requires a parenthesized set of values, found '{1}'")]
+    InvalidSet(&'static str, String),
+    #[error("unknown operator in requirement '{0}'")]
+    UnknownOperator(String),
+    #[error("unbalanced parentheses in selector '{0}'")]
+    UnbalancedParentheses(String),
+    #[error(
+        "invalid field selector requirement '{0}', expecting key=value, key==value or key!=value"
+    )]
+    InvalidFieldRequirement(String),
+}
+
+/// Validates label selector syntax, returns the selector unchanged when valid
+fn label_selector(text: &str) -> Result<String, SelectorError> {
+    requirements(text)?
+        .into_iter()
+        .try_for_each(label_requirement)?;
+    Ok(text.to_string())
+}
+
+/// Validates field selector syntax, returns the selector unchanged when valid
+fn field_selector(text: &str) -> Result<String, SelectorError> {
+    if !text.trim().is_empty() {
+        split_unescaped(text, ',')
+            .into_iter()
+            .try_for_each(field_requirement)?;
+    }
+    Ok(text.to_string())
+}
+
+/// Split selector into requirements on commas which are not inside a set
+fn requirements(text: &str) -> Result<Vec<&str>, SelectorError> {
+    if text.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut requirements = vec![];
+    for (idx, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| SelectorError::UnbalancedParentheses(text.to_string()))?
+            }
+            ',' if depth == 0 => {
+                requirements.push(&text[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err(SelectorError::UnbalancedParentheses(text.to_string()));
+    }
+
+    requirements.push(&text[start..]);
+    Ok(requirements)
+}
+
+fn label_requirement(requirement: &str) -> Result<(), SelectorError> {
+    let text = requirement.trim();
+    if text.is_empty() {
+        return Err(SelectorError::EmptyRequirement(requirement.to_string()));
+    }
+
+    // !key
+    if let Some(key) = text.strip_prefix('!') {
+        return label_key(key.trim());
+    }
+
+    let end = text
+        .find(|c: char| "=!<>(".contains(c) || c.is_whitespace())
+        .unwrap_or(text.len());
+    let (key, rest) = text.split_at(end);
+    label_key(key)?;
+
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        // key exists
+        Ok(())
+    } else if let Some(value) = ["==", "!=", "="]
+        .iter()
+        .find_map(|op| rest.strip_prefix(op))
+    {
+        label_value(value.trim())
+    } else if let Some((op, value)) = ["<", ">"]
+        .iter()
+        .find_map(|op| rest.strip_prefix(op).map(|value| (*op, value.trim())))
+    {
+        value
+            .parse::<i64>()
+            .map(|_| ())
+            .map_err(|_| SelectorError::NotAnInteger(op, value.to_string()))
+    } else if let Some((op, set)) = ["notin", "in"].iter().find_map(|op| {
+        rest.strip_prefix(op)
+            .filter(|set| set.starts_with(|c: char| c == '(' || c.is_whitespace()))
+            .map(|set| (*op, set.trim()))
+    }) {
+        label_set(op, set)
+    } else {
+        Err(SelectorError::UnknownOperator(text.to_string()))
+    }
+}
+
+fn label_set(op: &'static str, set: &str) -> Result<(), SelectorError> {
+    let values = set
+        .strip_prefix('(')
+        .and_then(|set| set.strip_suffix(')'))
+        .filter(|values| !values.trim().is_empty())
+        .ok_or_else(|| SelectorError::InvalidSet(op, set.to_string()))?;
+    values.split(',').map(str::trim).try_for_each(label_value)
+}
+
+// [prefix/]name where prefix is a DNS subdomain and name is a qualified name segment
+fn label_key(key: &str) -> Result<(), SelectorError> {
+    let invalid = || SelectorError::InvalidKey(key.to_string());
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+
+    if let Some(prefix) = prefix {
+        let valid = !prefix.is_empty()
+            && prefix.len() <= 253
+            && prefix.split('.').all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && label
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+            });
+        if !valid {
+            return Err(invalid());
+        }
+    }
+
+    if name.is_empty() || !is_qualified_segment(name) {
+        Err(invalid())
+    } else {
+        Ok(())
+    }
+}
+
+fn label_value(value: &str) -> Result<(), SelectorError> {
+    if value.is_empty() || is_qualified_segment(value) {
+        Ok(())
+    } else {
+        Err(SelectorError::InvalidValue(value.to_string()))
+    }
+}
+
+// Up to 63 alphanumeric characters, '-', '_' or '.', starting and ending with alphanumeric
+fn is_qualified_segment(text: &str) -> bool {
+    text.len() <= 63
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+        && text.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && text.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+fn field_requirement(requirement: &str) -> Result<(), SelectorError> {
+    let invalid = || SelectorError::InvalidFieldRequirement(requirement.to_string());
+    let (key, _value) = ["!=", "==", "="]
+        .iter()
+        .find_map(|op| split_once_unescaped(requirement, op))
+        .ok_or_else(invalid)?;
+    if key.trim().is_empty() {
+        Err(invalid())
+    } else {
+        Ok(())
+    }
+}
+
+// Field selector values may contain '\,', '\=' and '\\' escape sequences
+fn split_unescaped(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut escaped = false;
+    for (idx, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == separator => {
+                parts.push(&text[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn split_once_unescaped<'a>(text: &'a str, op: &str) -> Option<(&'a str, &'a str)> {
+    let mut escaped = false;
+    for (idx, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if text[idx..].starts_with(op) {
+            return Some((&text[..idx], &text[idx + op.len()..]));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_label_selectors() {
+        for selector in [
+            "",
+            "app",
+            "!app",
+            "app=nginx",
+            "app==nginx",
+            "app!=nginx",
+            "app=",
+            "tier in (frontend,backend)",
+            "tier notin (frontend, backend)",
+            "tier in(frontend)",
+            "app.kubernetes.io/name=nginx,environment!=prod",
+            "app=nginx,tier in (web),!canary",
+            "replicas>1",
+            "replicas < 10",
+        ] {
+            assert_eq!(
+                label_selector(selector).as_deref(),
+                Ok(selector),
+                "{selector}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_label_selectors() {
+        for selector in [
+            "app=nginx,",
+            ",app",
+            "app=-nginx",
+            "-app=nginx",
+            "Example.COM/app=nginx",
+            "app=nginx=web",
+            "tier in frontend",
+            "tier in ()",
+            "tier in (frontend",
+            "tier notin (frontend))",
+            "replicas>one",
+            "app ~ nginx",
+        ] {
+            assert!(label_selector(selector).is_err(), "{selector}");
+        }
+    }
+
+    #[test]
+    fn valid_field_selectors() {
+        for selector in [
+            "metadata.name=nginx",
+            "metadata.name==nginx",
+            "status.phase!=Running",
+            "metadata.namespace=default,spec.nodeName=",
+            r"metadata.name=a\,b",
+        ] {
+            assert_eq!(
+                field_selector(selector).as_deref(),
+                Ok(selector),
+                "{selector}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_field_selectors() {
+        for selector in [
+            "metadata.name",
+            "=nginx",
+            "status.phase in (Running)",
+            "a=b,",
+        ] {
+            assert!(field_selector(selector).is_err(), "{selector}");
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use futures_util::stream;
 use futures_util::stream::StreamExt;
-use futures_util::stream::TryStreamExt;
 
 use super::*;
 
@@ -13,36 +12,81 @@ impl Kubeapi {
         }
     }
 
+    /// Run live discovery unless the cache already holds fresh results.
+    /// Unreachable or unsupported endpoints are not fatal, callers simply keep working
+    /// without discovery. Being refused by the server is, it affects every other request too.
+    pub async fn discover(self) -> kube::Result<Self> {
+        if !self.cache.is_empty() {
+            return Ok(self);
+        }
+        match self.get_server_discovery().await {
+            Ok((groups, resources)) => {
+                let cache = self.cache.clone().update(groups, resources);
+                Ok(Self { cache, ..self })
+            }
+            Err(kube::Error::Api(response)) if matches!(response.code, 401 | 403) => {
+                Err(kube::Error::Api(response))
+            }
+            Err(err) => {
+                debug!(%err, "Discovery failed");
+                Ok(self)
+            }
+        }
+    }
+
     pub async fn server_api_resources(&self) -> kube::Result<Vec<metav1::APIResourceList>> {
         if let Some(resources) = self.cache.api_resources() {
             // resources.sort_by_key(|arl| arl.resources[0].group.as_deref());
             Ok(resources)
         } else {
-            self.get_server_api_resources().await
+            self.get_server_discovery()
+                .await
+                .map(|(_groups, resources)| resources)
         }
     }
 
-    async fn get_server_api_resources(&self) -> kube::Result<Vec<metav1::APIResourceList>> {
-        let client = self.client()?;
-        let core = client.list_core_api_versions().await?;
-        let core = stream::iter(&core.versions)
-            .then(|version| client.list_core_api_resources(version))
-            .try_collect::<Vec<_>>()
-            .await?;
-
-        let groups = client.list_api_groups().await?.groups;
-        let apiversions = groups.iter().filter_map(|group| {
-            group
-                .preferred_version
-                .as_ref()
-                .or_else(|| group.versions.first())
-        });
-        let groups = stream::iter(apiversions)
-            .then(|apiversion| client.list_api_group_resources(&apiversion.group_version))
-            .try_collect::<Vec<_>>()
-            .await?;
+    async fn get_server_discovery(
+        &self,
+    ) -> kube::Result<(metav1::APIGroupList, Vec<metav1::APIResourceList>)> {
+        match self.aggregated_discovery().await {
+            Ok(Some(discovery)) => return Ok(discovery),
+            Ok(None) => debug!("Aggregated discovery is not supported"),
+            Err(err) => debug!(%err, "Aggregated discovery failed"),
+        }
+        let groups = self.get_server_api_groups().await?;
+        let resources = self.get_server_api_resources(&groups).await?;
+        Ok((groups, resources))
+    }
 
-        Ok(core.into_iter().chain(groups).collect())
+    /// Groups that fail to list their resources, e.g. an unavailable aggregated API,
+    /// are skipped so that the rest of discovery is still usable
+    async fn get_server_api_resources(
+        &self,
+        groups: &metav1::APIGroupList,
+    ) -> kube::Result<Vec<metav1::APIResourceList>> {
+        let client = &self.client()?;
+        let resources = stream::iter(discovery_versions(groups))
+            .map(|(core, version)| async move {
+                let resources = if core {
+                    client.list_core_api_resources(&version.version).await
+                } else {
+                    client
+                        .list_api_group_resources(&version.group_version)
+                        .await
+                };
+                (version, resources)
+            })
+            .buffered(self.max_concurrent_requests())
+            .filter_map(|(version, resources)| async move {
+                resources
+                    .inspect_err(|err| {
+                        warn!(%err, group_version = version.group_version, "Skipping API group");
+                    })
+                    .ok()
+            })
+            .collect()
+            .await;
+        Ok(resources)
     }
 
     async fn get_server_api_groups(&self) -> kube::Result<metav1::APIGroupList> {
@@ -77,3 +121,67 @@ impl Kubeapi {
         Ok(core)
     }
 }
+
+/// Every version of the core group and the preferred version of the other groups,
+/// telling the core ones apart as they are listed from a different path
+fn discovery_versions(
+    groups: &metav1::APIGroupList,
+) -> impl Iterator<Item = (bool, &metav1::GroupVersionForDiscovery)> {
+    groups.groups.iter().flat_map(|group| {
+        let core = group.name == kube::discovery::ApiGroup::CORE_GROUP;
+        let versions = if core {
+            group.versions.iter().collect()
+        } else {
+            group
+                .preferred_version
+                .as_ref()
+                .or_else(|| group.versions.first())
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        versions.into_iter().map(move |version| (core, version))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions() {
+        let version = |group_version: &str| metav1::GroupVersionForDiscovery {
+            group_version: group_version.to_string(),
+            version: group_version.rsplit('/').next().unwrap().to_string(),
+        };
+        let group = |name: &str, versions: &[&str], preferred: Option<&str>| metav1::APIGroup {
+            name: name.to_string(),
+            preferred_version: preferred.map(version),
+            versions: versions.iter().copied().map(version).collect(),
+            ..Default::default()
+        };
+        let groups = metav1::APIGroupList {
+            groups: vec![
+                group("", &["v1"], Some("v1")),
+                group("apps", &["apps/v1"], Some("apps/v1")),
+                group(
+                    "autoscaling",
+                    &["autoscaling/v2", "autoscaling/v1"],
+                    Some("autoscaling/v2"),
+                ),
+                group("example.com", &["example.com/v1beta1"], None),
+            ],
+        };
+        let versions = discovery_versions(&groups)
+            .map(|(core, version)| (core, version.group_version.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            versions,
+            [
+                (true, "v1"),
+                (false, "apps/v1"),
+                (false, "autoscaling/v2"),
+                (false, "example.com/v1beta1")
+            ]
+        );
+    }
+}
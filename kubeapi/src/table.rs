@@ -0,0 +1,139 @@
+use http::HeaderValue;
+use http::header;
+
+use super::*;
+
+/// Server response to a request for a `Table`
+#[derive(Debug)]
+pub enum Printed<T> {
+    /// Server rendered `Table`
+    Table(Box<Table>),
+    /// Server ignored `as=Table` and answered with plain objects
+    Plain(T),
+}
+
+/// Server-side printing
+impl Kubeapi {
+    /// Ask the server to render the response as `meta.k8s.io/v1` `Table`
+    /// falling back to plain JSON if the server does not support it
+    const AS_TABLE: &str = "application/json;as=Table;g=meta.k8s.io;v=v1,application/json";
+
+    /// List resources as a server rendered `Table`.
+    pub async fn list_table(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+    ) -> kube::Result<Printed<api::ObjectList<api::DynamicObject>>> {
+        let lp = self.list_params();
+        let request = self
+            .dynamic_request(scope, dyntype)
+            .list(&lp)
+            .map_err(kube::Error::BuildRequest)?;
+        self.table(request).await
+    }
+
+    /// Get a single resource as a server rendered `Table`.
+    pub async fn get_table(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+        name: &str,
+    ) -> kube::Result<Printed<api::DynamicObject>> {
+        let gp = self.get_params();
+        let request = self
+            .dynamic_request(scope, dyntype)
+            .get(name, &gp)
+            .map_err(kube::Error::BuildRequest)?;
+        self.table(request).await
+    }
+
+    async fn table<T>(&self, mut request: http::Request<Vec<u8>>) -> kube::Result<Printed<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let include = if self.server_print_objects {
+            "Object"
+        } else {
+            "Metadata"
+        };
+        include_object(&mut request, include)?;
+        request
+            .headers_mut()
+            .insert(header::ACCEPT, HeaderValue::from_static(Self::AS_TABLE));
+        let text = self.client()?.request_text(request).await?;
+        parse_table(&text)
+    }
+
+    fn dynamic_request(
+        &self,
+        scope: discovery::Scope,
+        dyntype: &discovery::ApiResource,
+    ) -> api::Request {
+        let namespace = match scope {
+            discovery::Scope::Cluster => None,
+            discovery::Scope::Namespaced => match &self.namespace {
+                Namespace::All => None,
+                Namespace::Default => Some(self.config.default_namespace.as_str()),
+                Namespace::Namespace(namespace) => Some(namespace.as_str()),
+            },
+        };
+        let url_path = <api::DynamicObject as kube::Resource>::url_path(dyntype, namespace);
+        api::Request::new(url_path)
+    }
+}
+
+/// Every `Table` field has a default, so anything parses as one, check the kind
+fn parse_table<T>(text: &str) -> kube::Result<Printed<T>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value = json::from_str::<json::Value>(text).map_err(kube::Error::SerdeError)?;
+    if value["kind"] == "Table" {
+        json::from_value(value).map(|table| Printed::Table(Box::new(table)))
+    } else {
+        json::from_value(value).map(Printed::Plain)
+    }
+    .map_err(kube::Error::SerdeError)
+}
+
+/// Rows should carry object metadata at least, so that NAMESPACE column can be populated
+fn include_object(request: &mut http::Request<Vec<u8>>, include: &str) -> kube::Result<()> {
+    let uri = request.uri().to_string();
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    *request.uri_mut() = format!("{uri}{separator}includeObject={include}")
+        .parse()
+        .map_err(|err: http::uri::InvalidUri| {
+            kube::Error::BuildRequest(kube::core::request::Error::BuildRequest(err.into()))
+        })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn include() {
+        let mut request = http::Request::get("/api/v1/pods?limit=500")
+            .body(vec![])
+            .unwrap();
+        include_object(&mut request, "Object").unwrap();
+        assert_eq!(
+            request.uri().to_string(),
+            "/api/v1/pods?limit=500&includeObject=Object"
+        );
+    }
+
+    type List = api::ObjectList<api::DynamicObject>;
+
+    #[test]
+    fn not_a_table() {
+        let table = r#"{"kind": "Table", "apiVersion": "meta.k8s.io/v1", "columnDefinitions": [], "rows": []}"#;
+        assert!(matches!(parse_table::<List>(table), Ok(Printed::Table(_))));
+        let list = r#"{"kind": "WidgetList", "apiVersion": "example.com/v1", "metadata": {}, "items": [{"metadata": {"name": "gear"}}]}"#;
+        let Ok(Printed::Plain(list)) = parse_table::<List>(list) else {
+            panic!("expected plain list");
+        };
+        assert_eq!(list.items[0].metadata.name.as_deref(), Some("gear"));
+    }
+}
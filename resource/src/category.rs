@@ -0,0 +1,82 @@
+use super::*;
+
+/// Resources that belong to `category`, in discovery order.
+/// Only the preferred version of every resource is kept.
+pub(crate) fn members(
+    category: &str,
+    arls: &[metav1::APIResourceList],
+) -> Vec<(discovery::Scope, api::ApiResource)> {
+    let mut found = Vec::<(discovery::Scope, api::ApiResource)>::new();
+    for arl in arls {
+        let Ok(gv) = arl.group_version() else {
+            continue;
+        };
+        let resources = arl
+            .resources
+            .iter()
+            .filter(|ar| !ar.name.contains('/'))
+            .filter(|ar| ar.categories.iter().flatten().any(|text| text == category))
+            .map(|ar| (ar.scope(), ar.clone().kube_api_resource(gv.clone())));
+        for (scope, resource) in resources {
+            if !found
+                .iter()
+                .any(|(_, other)| other.group == resource.group && other.plural == resource.plural)
+            {
+                found.push((scope, resource));
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arl(group_version: &str, resources: &[(&str, &[&str])]) -> metav1::APIResourceList {
+        let resources = resources
+            .iter()
+            .map(|(name, categories)| metav1::APIResource {
+                name: name.to_string(),
+                kind: name.to_string(),
+                categories: Some(categories.iter().map(ToString::to_string).collect()),
+                ..Default::default()
+            })
+            .collect();
+        metav1::APIResourceList {
+            group_version: group_version.to_string(),
+            resources,
+        }
+    }
+
+    #[test]
+    fn preferred_only() {
+        let arls = [
+            arl(
+                "networking.istio.io/v1",
+                &[("gateways", &["istio-io"]), ("sidecars", &["istio-io"])],
+            ),
+            arl(
+                "networking.istio.io/v1beta1",
+                &[("gateways", &["istio-io"])],
+            ),
+            arl(
+                "security.istio.io/v1",
+                &[("peerauthentications", &["istio-io"])],
+            ),
+            arl("example.com/v1", &[("widgets", &[])]),
+        ];
+        let members = members("istio-io", &arls)
+            .into_iter()
+            .map(|(_, resource)| format!("{}.{}", resource.plural, resource.api_version))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            members,
+            [
+                "gateways.networking.istio.io/v1",
+                "sidecars.networking.istio.io/v1",
+                "peerauthentications.security.istio.io/v1"
+            ]
+        );
+    }
+}
@@ -1,23 +1,41 @@
 use std::fmt;
+use std::iter;
 
 use k8s_openapi_ext as k8s;
+use kube::ResourceExt;
 use kube::api;
 use kube::discovery;
 
 // use k8s::authenticationv1;
 // use k8s::authorizationv1;
+use k8s::appsv1;
+use k8s::autoscalingv2;
+use k8s::batchv1;
 use k8s::corev1;
-// use k8s::metav1;
-// use k8s::rbacv1;
+use k8s::metav1;
+use k8s::openapi::api::networking::v1 as networkingv1;
+use k8s::policyv1;
+use k8s::rbacv1;
+use k8s::storagev1;
 
-// use rkubectl_ext::APIResourceExt;
+use rkubectl_ext::APIResourceExt;
 use rkubectl_ext::APIResourceListExt;
 use rkubectl_kubeapi::Kubeapi;
+use rkubectl_kubeapi::Namespace;
+use rkubectl_kubeapi::Printed;
+use rkubectl_ui::Deleted;
 use rkubectl_ui::Show;
 
 pub use named::NamedResource;
+pub use watch::WatchStream;
 
+use name::ResourceName;
+
+mod category;
+mod name;
 mod named;
+mod suggest;
+mod watch;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ResourceArg {
@@ -29,21 +47,35 @@ impl ResourceArg {
     pub fn from_strings(
         resources: &[String],
         kubeapi: &Kubeapi,
-    ) -> Result<Vec<Self>, InvalidResourceSpec> {
+    ) -> Result<Vec<Self>, ResourceError> {
+        Self::expand_strings(resources, kubeapi).map(|(resources, _category)| resources)
+    }
+
+    /// Like [`ResourceArg::from_strings`], also telling whether a category was expanded
+    pub fn expand_strings(
+        resources: &[String],
+        kubeapi: &Kubeapi,
+    ) -> Result<(Vec<Self>, bool), ResourceError> {
         // Two possible formats
         // 1. resource/name - in which case all the items should be the same
         // 2. resource[,resource,..] [name] [..]
         if resources.iter().any(|resource| resource.contains('/')) {
-            resources
+            let resources = resources
                 .iter()
                 .map(|text| Self::named_resource(text, kubeapi))
-                .collect()
+                .collect::<Result<_, _>>()?;
+            Ok((resources, false))
         } else {
             let (resource, names) = resources.split_first().ok_or(InvalidResourceSpec)?;
-            let resources = resource
+            let expanded = resource
                 .split(",")
-                .map(|resource| Resource::with_cache(resource, kubeapi).ok_or(InvalidResourceSpec))
+                .map(|resource| Resource::expand(resource, kubeapi))
                 .collect::<Result<Vec<_>, _>>()?;
+            let category = expanded.iter().any(Expanded::is_category);
+            let resources = expanded
+                .into_iter()
+                .flat_map(Expanded::into_resources)
+                .collect::<Vec<_>>();
             let resources = if names.is_empty() {
                 // Just resources, no names
                 resources.into_iter().map(ResourceArg::Resource).collect()
@@ -58,19 +90,15 @@ impl ResourceArg {
                     .map(Self::NamedResource)
                     .collect()
             };
-            Ok(resources)
+            Ok((resources, category))
         }
     }
 
-    fn named_resource(
-        text: impl AsRef<str>,
-        kubeapi: &Kubeapi,
-    ) -> Result<Self, InvalidResourceSpec> {
+    fn named_resource(text: impl AsRef<str>, kubeapi: &Kubeapi) -> Result<Self, ResourceError> {
         let (resource, name) = text.as_ref().split_once("/").ok_or(InvalidResourceSpec)?;
         Resource::with_cache(resource, kubeapi)
             .map(|resource| NamedResource::with_resource(resource, name))
             .map(Self::NamedResource)
-            .ok_or(InvalidResourceSpec)
     }
 
     pub async fn get(&self, kubeapi: &Kubeapi) -> kube::Result<Box<dyn Show>> {
@@ -90,15 +118,15 @@ impl ResourceArg {
         kubeapi: &Kubeapi,
         dp: &api::DeleteParams,
         all: bool,
-    ) -> kube::Result<()> {
+    ) -> kube::Result<Vec<Deleted<api::DynamicObject>>> {
         match self {
-            Self::Resource(resource) if all => {
-                todo!("Deleting ALL resources {resource:?} is not implemented yet")
+            Self::Resource(resource) if all || !kubeapi.selectors().is_empty() => {
+                resource.delete_list(kubeapi, dp).await
             }
             Self::Resource(resource) => {
                 todo!("Deleting SOME resources {resource:?} is not implemented yet")
             }
-            Self::NamedResource(resource) => resource.delete(kubeapi, dp).await,
+            Self::NamedResource(resource) => resource.delete(kubeapi, dp).await.map(|k| vec![k]),
         }
     }
 
@@ -129,10 +157,40 @@ impl fmt::Display for ResourceArg {
 #[derive(Clone, Debug, PartialEq)]
 pub enum Resource {
     Pods,
+    Services,
     Namespaces,
     Nodes,
     ConfigMaps,
+    Secrets,
+    ServiceAccounts,
+    Endpoints,
+    Events,
+    PersistentVolumes,
+    PersistentVolumeClaims,
+    ReplicationControllers,
+    ResourceQuotas,
+    LimitRanges,
     ComponentStatuses,
+    Deployments,
+    ReplicaSets,
+    StatefulSets,
+    DaemonSets,
+    ControllerRevisions,
+    Jobs,
+    CronJobs,
+    Ingresses,
+    IngressClasses,
+    NetworkPolicies,
+    Roles,
+    RoleBindings,
+    ClusterRoles,
+    ClusterRoleBindings,
+    PodDisruptionBudgets,
+    StorageClasses,
+    CsiDrivers,
+    CsiNodes,
+    VolumeAttachments,
+    HorizontalPodAutoscalers,
     Other {
         scope: discovery::Scope,
         resource: api::ApiResource,
@@ -140,78 +198,304 @@ pub enum Resource {
 }
 
 impl Resource {
-    pub fn with_cache(resource: &str, kubeapi: &Kubeapi) -> Option<Self> {
-        Self::well_known(resource).or_else(|| Self::other(resource, kubeapi))
+    /// Every name accepted by [`Resource::well_known`] along with the resource it stands for
+    pub const WELL_KNOWN: &[(&str, Self)] = &[
+        ("po", Self::Pods),
+        ("pod", Self::Pods),
+        ("pods", Self::Pods),
+        ("svc", Self::Services),
+        ("service", Self::Services),
+        ("services", Self::Services),
+        ("ns", Self::Namespaces),
+        ("namespace", Self::Namespaces),
+        ("namespaces", Self::Namespaces),
+        ("no", Self::Nodes),
+        ("node", Self::Nodes),
+        ("nodes", Self::Nodes),
+        ("cm", Self::ConfigMaps),
+        ("configmap", Self::ConfigMaps),
+        ("configmaps", Self::ConfigMaps),
+        ("secret", Self::Secrets),
+        ("secrets", Self::Secrets),
+        ("sa", Self::ServiceAccounts),
+        ("serviceaccount", Self::ServiceAccounts),
+        ("serviceaccounts", Self::ServiceAccounts),
+        ("ep", Self::Endpoints),
+        ("endpoints", Self::Endpoints),
+        ("ev", Self::Events),
+        ("event", Self::Events),
+        ("events", Self::Events),
+        ("pv", Self::PersistentVolumes),
+        ("persistentvolume", Self::PersistentVolumes),
+        ("persistentvolumes", Self::PersistentVolumes),
+        ("pvc", Self::PersistentVolumeClaims),
+        ("persistentvolumeclaim", Self::PersistentVolumeClaims),
+        ("persistentvolumeclaims", Self::PersistentVolumeClaims),
+        ("rc", Self::ReplicationControllers),
+        ("replicationcontroller", Self::ReplicationControllers),
+        ("replicationcontrollers", Self::ReplicationControllers),
+        ("quota", Self::ResourceQuotas),
+        ("resourcequota", Self::ResourceQuotas),
+        ("resourcequotas", Self::ResourceQuotas),
+        ("limits", Self::LimitRanges),
+        ("limitrange", Self::LimitRanges),
+        ("limitranges", Self::LimitRanges),
+        ("cs", Self::ComponentStatuses),
+        ("componentstatus", Self::ComponentStatuses),
+        ("componentstatuses", Self::ComponentStatuses),
+        ("deploy", Self::Deployments),
+        ("deployment", Self::Deployments),
+        ("deployments", Self::Deployments),
+        ("rs", Self::ReplicaSets),
+        ("replicaset", Self::ReplicaSets),
+        ("replicasets", Self::ReplicaSets),
+        ("sts", Self::StatefulSets),
+        ("statefulset", Self::StatefulSets),
+        ("statefulsets", Self::StatefulSets),
+        ("ds", Self::DaemonSets),
+        ("daemonset", Self::DaemonSets),
+        ("daemonsets", Self::DaemonSets),
+        ("controllerrevision", Self::ControllerRevisions),
+        ("controllerrevisions", Self::ControllerRevisions),
+        ("job", Self::Jobs),
+        ("jobs", Self::Jobs),
+        ("cj", Self::CronJobs),
+        ("cronjob", Self::CronJobs),
+        ("cronjobs", Self::CronJobs),
+        ("ing", Self::Ingresses),
+        ("ingress", Self::Ingresses),
+        ("ingresses", Self::Ingresses),
+        ("ingressclass", Self::IngressClasses),
+        ("ingressclasses", Self::IngressClasses),
+        ("netpol", Self::NetworkPolicies),
+        ("networkpolicy", Self::NetworkPolicies),
+        ("networkpolicies", Self::NetworkPolicies),
+        ("role", Self::Roles),
+        ("roles", Self::Roles),
+        ("rolebinding", Self::RoleBindings),
+        ("rolebindings", Self::RoleBindings),
+        ("clusterrole", Self::ClusterRoles),
+        ("clusterroles", Self::ClusterRoles),
+        ("clusterrolebinding", Self::ClusterRoleBindings),
+        ("clusterrolebindings", Self::ClusterRoleBindings),
+        ("pdb", Self::PodDisruptionBudgets),
+        ("poddisruptionbudget", Self::PodDisruptionBudgets),
+        ("poddisruptionbudgets", Self::PodDisruptionBudgets),
+        ("sc", Self::StorageClasses),
+        ("storageclass", Self::StorageClasses),
+        ("storageclasses", Self::StorageClasses),
+        ("csidriver", Self::CsiDrivers),
+        ("csidrivers", Self::CsiDrivers),
+        ("csinode", Self::CsiNodes),
+        ("csinodes", Self::CsiNodes),
+        ("volumeattachment", Self::VolumeAttachments),
+        ("volumeattachments", Self::VolumeAttachments),
+        ("hpa", Self::HorizontalPodAutoscalers),
+        ("horizontalpodautoscaler", Self::HorizontalPodAutoscalers),
+        ("horizontalpodautoscalers", Self::HorizontalPodAutoscalers),
+    ];
+
+    /// What `all` stands for when there is no discovery information at hand
+    const ALL: &[Self] = &[
+        Self::Pods,
+        Self::ReplicationControllers,
+        Self::Services,
+        Self::DaemonSets,
+        Self::Deployments,
+        Self::ReplicaSets,
+        Self::StatefulSets,
+        Self::HorizontalPodAutoscalers,
+        Self::CronJobs,
+        Self::Jobs,
+    ];
+
+    /// Like [`Resource::with_cache`], but also expands categories such as `all`
+    pub fn expand(resource: &str, kubeapi: &Kubeapi) -> Result<Expanded, ResourceError> {
+        let name = ResourceName::parse(resource);
+        if let Some(resource) = Self::well_known_qualified(&name) {
+            return Ok(Expanded::Resource(resource));
+        }
+        let arls = kubeapi.cached_server_api_resources();
+        if let Some(resources) = Self::category(resource, &arls) {
+            Ok(Expanded::Category(resources))
+        } else {
+            Self::other(&name, arls).map(Expanded::Resource)
+        }
     }
 
-    pub fn well_known(text: &str) -> Option<Self> {
-        match text {
-            "po" | "pod" | "pods" => Some(Self::Pods),
-            "no" | "node" | "nodes" => Some(Self::Nodes),
-            "ns" | "namespace" | "namespaces" => Some(Self::Namespaces),
-            "cm" | "configmap" | "configmaps" => Some(Self::ConfigMaps),
-            "cs" | "componentstatus" | "componentstatuses" => Some(Self::ComponentStatuses),
-            _ => None,
+    /// Resolve `TYPE[.VERSION][.GROUP]`, falling back to the cached discovery
+    /// information for anything that is not well known
+    pub fn with_cache(resource: &str, kubeapi: &Kubeapi) -> Result<Self, ResourceError> {
+        let name = ResourceName::parse(resource);
+        if let Some(resource) = Self::well_known_qualified(&name) {
+            Ok(resource)
+        } else {
+            Self::other(&name, kubeapi.cached_server_api_resources())
         }
     }
 
+    pub fn well_known(text: &str) -> Option<Self> {
+        Self::WELL_KNOWN
+            .iter()
+            .find(|(alias, _)| *alias == text)
+            .map(|(_, resource)| resource.clone())
+    }
+
+    fn well_known_qualified(name: &ResourceName<'_>) -> Option<Self> {
+        Self::well_known(name.name).filter(|resource| {
+            let (_scope, resource) = resource.api_resource();
+            name.matches(&resource.group, &resource.version)
+        })
+    }
+
     async fn list(&self, kubeapi: &Kubeapi) -> kube::Result<Box<dyn Show>> {
         let lp = kubeapi.list_params();
         match self {
-            Self::Pods => {
-                let list = kubeapi.pods()?.list(&lp).await?;
-                Ok(Box::new(list))
-            }
-            Self::Namespaces => {
-                let list = kubeapi.namespaces()?.list(&lp).await?;
-                Ok(Box::new(list))
-            }
-            Self::Nodes => {
-                let list = kubeapi.nodes()?.list(&lp).await?;
-                Ok(Box::new(list))
-            }
-            Self::ConfigMaps => {
-                let list = kubeapi.configmaps()?.list(&lp).await?;
-                Ok(Box::new(list))
-            }
-            Self::ComponentStatuses => {
-                let list = kubeapi.componentstatuses()?.list(&lp).await?;
-                Ok(Box::new(list))
-            }
+            Self::Pods => list(kubeapi.pods()?, &lp).await,
+            Self::Services => list(kubeapi.services()?, &lp).await,
+            Self::Namespaces => list(kubeapi.namespaces()?, &lp).await,
+            Self::Nodes => list(kubeapi.nodes()?, &lp).await,
+            Self::ConfigMaps => list(kubeapi.configmaps()?, &lp).await,
+            Self::Secrets => list(kubeapi.secrets()?, &lp).await,
+            Self::ServiceAccounts => list(kubeapi.serviceaccounts()?, &lp).await,
+            Self::Endpoints => list(kubeapi.endpoints()?, &lp).await,
+            Self::Events => list(kubeapi.events()?, &lp).await,
+            Self::PersistentVolumes => list(kubeapi.persistentvolumes()?, &lp).await,
+            Self::PersistentVolumeClaims => list(kubeapi.persistentvolumeclaims()?, &lp).await,
+            Self::ReplicationControllers => list(kubeapi.replicationcontrollers()?, &lp).await,
+            Self::ResourceQuotas => list(kubeapi.resourcequotas()?, &lp).await,
+            Self::LimitRanges => list(kubeapi.limitranges()?, &lp).await,
+            Self::ComponentStatuses => list(kubeapi.componentstatuses()?, &lp).await,
+            Self::Deployments => list(kubeapi.deployments()?, &lp).await,
+            Self::ReplicaSets => list(kubeapi.replicasets()?, &lp).await,
+            Self::StatefulSets => list(kubeapi.statefulsets()?, &lp).await,
+            Self::DaemonSets => list(kubeapi.daemonsets()?, &lp).await,
+            Self::ControllerRevisions => list(kubeapi.controllerrevisions()?, &lp).await,
+            Self::Jobs => list(kubeapi.jobs()?, &lp).await,
+            Self::CronJobs => list(kubeapi.cronjobs()?, &lp).await,
+            Self::Ingresses => list(kubeapi.ingresses()?, &lp).await,
+            Self::IngressClasses => list(kubeapi.ingressclasses()?, &lp).await,
+            Self::NetworkPolicies => list(kubeapi.networkpolicies()?, &lp).await,
+            Self::Roles => list(kubeapi.roles()?, &lp).await,
+            Self::RoleBindings => list(kubeapi.rolebindings()?, &lp).await,
+            Self::ClusterRoles => list(kubeapi.clusterroles()?, &lp).await,
+            Self::ClusterRoleBindings => list(kubeapi.clusterrolebindings()?, &lp).await,
+            Self::PodDisruptionBudgets => list(kubeapi.poddisruptionbudgets()?, &lp).await,
+            Self::StorageClasses => list(kubeapi.storageclasses()?, &lp).await,
+            Self::CsiDrivers => list(kubeapi.csidrivers()?, &lp).await,
+            Self::CsiNodes => list(kubeapi.csinodes()?, &lp).await,
+            Self::VolumeAttachments => list(kubeapi.volumeattachments()?, &lp).await,
+            Self::HorizontalPodAutoscalers => list(kubeapi.horizontalpodautoscalers()?, &lp).await,
             Self::Other { scope, resource } => {
-                todo!("list not implemented yet for {scope:?} {resource:?}")
+                let mut list = if kubeapi.server_print() {
+                    match kubeapi.list_table(scope.clone(), resource).await? {
+                        Printed::Table(table) => {
+                            return Ok(Box::new(table.with_kind(&resource.kind)));
+                        }
+                        Printed::Plain(list) => list,
+                    }
+                } else {
+                    kubeapi
+                        .dynamic_object_api(scope.clone(), resource)?
+                        .list(&lp)
+                        .await?
+                };
+                // List items come back without apiVersion/kind, restore them
+                // from the discovered resource so that they can be shown with kind
+                list.items
+                    .iter_mut()
+                    .for_each(|object| object.types = Some(Self::types(resource)));
+                Ok(Box::new(list))
             }
         }
     }
 
     async fn get(&self, kubeapi: &Kubeapi, name: &str) -> kube::Result<Box<dyn Show>> {
         match self {
-            Self::Pods => {
-                let obj = kubeapi.pods()?.get(name).await?;
-                Ok(Box::new(obj))
-            }
-            Self::Namespaces => {
-                let obj = kubeapi.namespaces()?.get(name).await?;
-                Ok(Box::new(obj))
-            }
-            Self::Nodes => {
-                let obj = kubeapi.nodes()?.get(name).await?;
-                Ok(Box::new(obj))
-            }
-            Self::ConfigMaps => {
-                let obj = kubeapi.configmaps()?.get(name).await?;
-                Ok(Box::new(obj))
-            }
-            Self::ComponentStatuses => {
-                let obj = kubeapi.componentstatuses()?.get(name).await?;
-                Ok(Box::new(obj))
-            }
+            Self::Pods => get(kubeapi.pods()?, name).await,
+            Self::Services => get(kubeapi.services()?, name).await,
+            Self::Namespaces => get(kubeapi.namespaces()?, name).await,
+            Self::Nodes => get(kubeapi.nodes()?, name).await,
+            Self::ConfigMaps => get(kubeapi.configmaps()?, name).await,
+            Self::Secrets => get(kubeapi.secrets()?, name).await,
+            Self::ServiceAccounts => get(kubeapi.serviceaccounts()?, name).await,
+            Self::Endpoints => get(kubeapi.endpoints()?, name).await,
+            Self::Events => get(kubeapi.events()?, name).await,
+            Self::PersistentVolumes => get(kubeapi.persistentvolumes()?, name).await,
+            Self::PersistentVolumeClaims => get(kubeapi.persistentvolumeclaims()?, name).await,
+            Self::ReplicationControllers => get(kubeapi.replicationcontrollers()?, name).await,
+            Self::ResourceQuotas => get(kubeapi.resourcequotas()?, name).await,
+            Self::LimitRanges => get(kubeapi.limitranges()?, name).await,
+            Self::ComponentStatuses => get(kubeapi.componentstatuses()?, name).await,
+            Self::Deployments => get(kubeapi.deployments()?, name).await,
+            Self::ReplicaSets => get(kubeapi.replicasets()?, name).await,
+            Self::StatefulSets => get(kubeapi.statefulsets()?, name).await,
+            Self::DaemonSets => get(kubeapi.daemonsets()?, name).await,
+            Self::ControllerRevisions => get(kubeapi.controllerrevisions()?, name).await,
+            Self::Jobs => get(kubeapi.jobs()?, name).await,
+            Self::CronJobs => get(kubeapi.cronjobs()?, name).await,
+            Self::Ingresses => get(kubeapi.ingresses()?, name).await,
+            Self::IngressClasses => get(kubeapi.ingressclasses()?, name).await,
+            Self::NetworkPolicies => get(kubeapi.networkpolicies()?, name).await,
+            Self::Roles => get(kubeapi.roles()?, name).await,
+            Self::RoleBindings => get(kubeapi.rolebindings()?, name).await,
+            Self::ClusterRoles => get(kubeapi.clusterroles()?, name).await,
+            Self::ClusterRoleBindings => get(kubeapi.clusterrolebindings()?, name).await,
+            Self::PodDisruptionBudgets => get(kubeapi.poddisruptionbudgets()?, name).await,
+            Self::StorageClasses => get(kubeapi.storageclasses()?, name).await,
+            Self::CsiDrivers => get(kubeapi.csidrivers()?, name).await,
+            Self::CsiNodes => get(kubeapi.csinodes()?, name).await,
+            Self::VolumeAttachments => get(kubeapi.volumeattachments()?, name).await,
+            Self::HorizontalPodAutoscalers => get(kubeapi.horizontalpodautoscalers()?, name).await,
             Self::Other { scope, resource } => {
-                todo!("get not implemented yet for {scope:?} {resource:?}")
+                let mut obj = if kubeapi.server_print() {
+                    match kubeapi.get_table(scope.clone(), resource, name).await? {
+                        Printed::Table(table) => {
+                            return Ok(Box::new(table.with_kind(&resource.kind)));
+                        }
+                        Printed::Plain(obj) => obj,
+                    }
+                } else {
+                    kubeapi
+                        .dynamic_object_api(scope.clone(), resource)?
+                        .get(name)
+                        .await?
+                };
+                obj.types.get_or_insert_with(|| Self::types(resource));
+                Ok(Box::new(obj))
             }
         }
     }
 
+    /// Delete every object returned by listing this resource with current selectors
+    async fn delete_list(
+        &self,
+        kubeapi: &Kubeapi,
+        dp: &api::DeleteParams,
+    ) -> kube::Result<Vec<Deleted<api::DynamicObject>>> {
+        let (scope, ref dyntype) = self.api_resource();
+        let lp = kubeapi.list_params();
+        let list = kubeapi
+            .dynamic_object_api(scope, dyntype)?
+            .list(&lp)
+            .await?;
+        let mut deleted = vec![];
+        for object in list {
+            // Objects may come from many namespaces when listing across all of them
+            let kubeapi = match object.namespace() {
+                Some(namespace) => kubeapi
+                    .clone()
+                    .with_namespace(Namespace::Namespace(namespace)),
+                None => kubeapi.clone(),
+            };
+            let k = NamedResource::with_resource(self.clone(), object.name_any())
+                .delete(&kubeapi, dp)
+                .await?;
+            deleted.push(k);
+        }
+        Ok(deleted)
+    }
+
     // async fn delete(
     //     &self,
     //     kubectl: &Kubectl,
@@ -245,24 +529,53 @@ impl Resource {
 
         match self {
             Self::Pods => (Namespaced, Self::erase::<corev1::Pod>()),
+            Self::Services => (Namespaced, Self::erase::<corev1::Service>()),
             Self::Namespaces => (Cluster, Self::erase::<corev1::Namespace>()),
             Self::Nodes => (Cluster, Self::erase::<corev1::Node>()),
             Self::ConfigMaps => (Namespaced, Self::erase::<corev1::ConfigMap>()),
-            Self::ComponentStatuses => (Namespaced, Self::erase::<corev1::ComponentStatus>()),
+            Self::Secrets => (Namespaced, Self::erase::<corev1::Secret>()),
+            Self::ServiceAccounts => (Namespaced, Self::erase::<corev1::ServiceAccount>()),
+            Self::Endpoints => (Namespaced, Self::erase::<corev1::Endpoints>()),
+            Self::Events => (Namespaced, Self::erase::<corev1::Event>()),
+            Self::PersistentVolumes => (Cluster, Self::erase::<corev1::PersistentVolume>()),
+            Self::PersistentVolumeClaims => {
+                (Namespaced, Self::erase::<corev1::PersistentVolumeClaim>())
+            }
+            Self::ReplicationControllers => {
+                (Namespaced, Self::erase::<corev1::ReplicationController>())
+            }
+            Self::ResourceQuotas => (Namespaced, Self::erase::<corev1::ResourceQuota>()),
+            Self::LimitRanges => (Namespaced, Self::erase::<corev1::LimitRange>()),
+            Self::ComponentStatuses => (Cluster, Self::erase::<corev1::ComponentStatus>()),
+            Self::Deployments => (Namespaced, Self::erase::<appsv1::Deployment>()),
+            Self::ReplicaSets => (Namespaced, Self::erase::<appsv1::ReplicaSet>()),
+            Self::StatefulSets => (Namespaced, Self::erase::<appsv1::StatefulSet>()),
+            Self::DaemonSets => (Namespaced, Self::erase::<appsv1::DaemonSet>()),
+            Self::ControllerRevisions => (Namespaced, Self::erase::<appsv1::ControllerRevision>()),
+            Self::Jobs => (Namespaced, Self::erase::<batchv1::Job>()),
+            Self::CronJobs => (Namespaced, Self::erase::<batchv1::CronJob>()),
+            Self::Ingresses => (Namespaced, Self::erase::<networkingv1::Ingress>()),
+            Self::IngressClasses => (Cluster, Self::erase::<networkingv1::IngressClass>()),
+            Self::NetworkPolicies => (Namespaced, Self::erase::<networkingv1::NetworkPolicy>()),
+            Self::Roles => (Namespaced, Self::erase::<rbacv1::Role>()),
+            Self::RoleBindings => (Namespaced, Self::erase::<rbacv1::RoleBinding>()),
+            Self::ClusterRoles => (Cluster, Self::erase::<rbacv1::ClusterRole>()),
+            Self::ClusterRoleBindings => (Cluster, Self::erase::<rbacv1::ClusterRoleBinding>()),
+            Self::PodDisruptionBudgets => {
+                (Namespaced, Self::erase::<policyv1::PodDisruptionBudget>())
+            }
+            Self::StorageClasses => (Cluster, Self::erase::<storagev1::StorageClass>()),
+            Self::CsiDrivers => (Cluster, Self::erase::<storagev1::CSIDriver>()),
+            Self::CsiNodes => (Cluster, Self::erase::<storagev1::CSINode>()),
+            Self::VolumeAttachments => (Cluster, Self::erase::<storagev1::VolumeAttachment>()),
+            Self::HorizontalPodAutoscalers => (
+                Namespaced,
+                Self::erase::<autoscalingv2::HorizontalPodAutoscaler>(),
+            ),
             Self::Other { scope, resource } => (scope.clone(), resource.clone()),
         }
     }
 
-    fn cached_dynamic_api_resource(
-        kubeapi: &Kubeapi,
-        name: &str,
-    ) -> Option<(discovery::Scope, api::ApiResource)> {
-        kubeapi
-            .cached_server_api_resources()
-            .into_iter()
-            .find_map(|arl| arl.kube_api_resource(name))
-    }
-
     fn erase<K>() -> api::ApiResource
     where
         K: kube::Resource,
@@ -271,9 +584,94 @@ impl Resource {
         api::ApiResource::erase::<K>(&<K as kube::Resource>::DynamicType::default())
     }
 
-    fn other(resource: &str, kubeapi: &Kubeapi) -> Option<Self> {
-        Self::cached_dynamic_api_resource(kubeapi, resource)
-            .map(|(scope, resource)| Self::Other { scope, resource })
+    fn types(resource: &api::ApiResource) -> kube::core::TypeMeta {
+        kube::core::TypeMeta {
+            api_version: resource.api_version.clone(),
+            kind: resource.kind.clone(),
+        }
+    }
+
+    fn category(category: &str, arls: &[metav1::APIResourceList]) -> Option<Vec<Self>> {
+        let members = category::members(category, arls);
+        if !members.is_empty() {
+            let resources = members
+                .into_iter()
+                .map(|(scope, resource)| Self::with_api_resource(scope, resource))
+                .collect();
+            Some(resources)
+        } else if category == "all" && arls.is_empty() {
+            Some(Self::ALL.to_vec())
+        } else {
+            None
+        }
+    }
+
+    /// Prefer the typed variant for discovered built-in resources
+    fn with_api_resource(scope: discovery::Scope, resource: api::ApiResource) -> Self {
+        Self::well_known(&resource.plural)
+            .filter(|known| {
+                let (_scope, known) = known.api_resource();
+                known.group == resource.group && known.version == resource.version
+            })
+            .unwrap_or(Self::Other { scope, resource })
+    }
+
+    fn other(
+        name: &ResourceName<'_>,
+        arls: Vec<metav1::APIResourceList>,
+    ) -> Result<Self, ResourceError> {
+        let mut found = name.resolve(&arls);
+        if found.is_empty() {
+            // Well known name with a group or version that doesn't match
+            if let Some(known) = Self::well_known(name.name) {
+                let (_scope, known) = known.api_resource();
+                return Err(ResourceError::mismatch(name, &known));
+            }
+            let known = Self::WELL_KNOWN
+                .iter()
+                .map(|(alias, _)| *alias)
+                .chain(suggest::cached_names(&arls));
+            let suggestions = suggest::suggestions(name.name, known);
+            let name = name.to_string();
+            Err(ResourceError::unknown(name, suggestions))
+        } else if found.len() > 1 {
+            let candidates = found
+                .iter()
+                .map(|(_scope, resource)| {
+                    ResourceName {
+                        name: &resource.plural,
+                        version: None,
+                        group: Some(resource.group.as_str()).filter(|group| !group.is_empty()),
+                    }
+                    .to_string()
+                })
+                .collect();
+            let name = name.to_string();
+            Err(ResourceError::Ambiguous { name, candidates })
+        } else {
+            let (scope, resource) = found.remove(0);
+            Ok(Self::Other { scope, resource })
+        }
+    }
+}
+
+/// What a single `TYPE` argument stands for
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expanded {
+    Resource(Resource),
+    Category(Vec<Resource>),
+}
+
+impl Expanded {
+    pub fn is_category(&self) -> bool {
+        matches!(self, Self::Category(_))
+    }
+
+    pub fn into_resources(self) -> Vec<Resource> {
+        match self {
+            Self::Resource(resource) => vec![resource],
+            Self::Category(resources) => resources,
+        }
     }
 }
 
@@ -281,26 +679,131 @@ impl fmt::Display for Resource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Pods => "pod".fmt(f),
+            Self::Services => "service".fmt(f),
             Self::Namespaces => "namespace".fmt(f),
             Self::Nodes => "node".fmt(f),
             Self::ConfigMaps => "configmap".fmt(f),
+            Self::Secrets => "secret".fmt(f),
+            Self::ServiceAccounts => "serviceaccount".fmt(f),
+            Self::Endpoints => "endpoints".fmt(f),
+            Self::Events => "event".fmt(f),
+            Self::PersistentVolumes => "persistentvolume".fmt(f),
+            Self::PersistentVolumeClaims => "persistentvolumeclaim".fmt(f),
+            Self::ReplicationControllers => "replicationcontroller".fmt(f),
+            Self::ResourceQuotas => "resourcequota".fmt(f),
+            Self::LimitRanges => "limitrange".fmt(f),
             Self::ComponentStatuses => "componentstatus".fmt(f),
+            Self::Deployments => "deployment".fmt(f),
+            Self::ReplicaSets => "replicaset".fmt(f),
+            Self::StatefulSets => "statefulset".fmt(f),
+            Self::DaemonSets => "daemonset".fmt(f),
+            Self::ControllerRevisions => "controllerrevision".fmt(f),
+            Self::Jobs => "job".fmt(f),
+            Self::CronJobs => "cronjob".fmt(f),
+            Self::Ingresses => "ingress".fmt(f),
+            Self::IngressClasses => "ingressclass".fmt(f),
+            Self::NetworkPolicies => "networkpolicy".fmt(f),
+            Self::Roles => "role".fmt(f),
+            Self::RoleBindings => "rolebinding".fmt(f),
+            Self::ClusterRoles => "clusterrole".fmt(f),
+            Self::ClusterRoleBindings => "clusterrolebinding".fmt(f),
+            Self::PodDisruptionBudgets => "poddisruptionbudget".fmt(f),
+            Self::StorageClasses => "storageclass".fmt(f),
+            Self::CsiDrivers => "csidriver".fmt(f),
+            Self::CsiNodes => "csinode".fmt(f),
+            Self::VolumeAttachments => "volumeattachment".fmt(f),
+            Self::HorizontalPodAutoscalers => "horizontalpodautoscaler".fmt(f),
             Self::Other { resource, .. } => resource.kind.to_lowercase().fmt(f),
         }
     }
 }
 
+async fn list<K>(api: api::Api<K>, lp: &api::ListParams) -> kube::Result<Box<dyn Show>>
+where
+    K: Clone + fmt::Debug + Show + kube::Resource + serde::Serialize + serde::de::DeserializeOwned,
+    K: 'static,
+{
+    let list = api.list(lp).await?;
+    Ok(Box::new(list))
+}
+
+async fn get<K>(api: api::Api<K>, name: &str) -> kube::Result<Box<dyn Show>>
+where
+    K: Clone + fmt::Debug + Show + kube::Resource + serde::de::DeserializeOwned + 'static,
+{
+    let obj = api.get(name).await?;
+    Ok(Box::new(obj))
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(
     "there is no need to specify a resource type as a separate argument when passing arguments in resource/name form (e.g. 'kubectl get resource/<resource_name>' instead of 'kubectl get resource resource/<resource_name>')"
 )]
 pub struct InvalidResourceSpec;
 
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+pub enum ResourceError {
+    #[error(transparent)]
+    InvalidSpec(#[from] InvalidResourceSpec),
+    #[error("the server doesn't have a resource type \"{name}\"")]
+    Unknown {
+        name: String,
+        #[help]
+        help: Option<String>,
+    },
+    #[error(
+        "resource type \"{name}\" is ambiguous, specify one of: {}",
+        .candidates.join(", ")
+    )]
+    Ambiguous {
+        name: String,
+        candidates: Vec<String>,
+    },
+}
+
+impl ResourceError {
+    fn unknown(name: String, suggestions: Vec<String>) -> Self {
+        let help = match suggestions.as_slice() {
+            [] => None,
+            [suggestion] => Some(format!("did you mean \"{suggestion}\"?")),
+            suggestions => Some(format!("did you mean one of: {}?", suggestions.join(", "))),
+        };
+        Self::Unknown { name, help }
+    }
+
+    fn mismatch(name: &ResourceName<'_>, known: &api::ApiResource) -> Self {
+        let alias = name.name;
+        let help = match name.group {
+            Some(group) if group != known.group && known.group.is_empty() => {
+                format!("\"{alias}\" is in the core group, not \"{group}\"")
+            }
+            Some(group) if group != known.group => {
+                format!(
+                    "\"{alias}\" is in the \"{}\" group, not \"{group}\"",
+                    known.group
+                )
+            }
+            _ => {
+                let version = name.version.unwrap_or_default();
+                format!(
+                    "\"{alias}\" is served as \"{}\", not \"{version}\"",
+                    known.version
+                )
+            }
+        };
+        let name = name.to_string();
+        Self::Unknown {
+            name,
+            help: Some(help),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn args(s: &[&str]) -> Result<Vec<ResourceArg>, InvalidResourceSpec> {
+    fn args(s: &[&str]) -> Result<Vec<ResourceArg>, ResourceError> {
         let resources = s.iter().map(ToString::to_string).collect::<Vec<_>>();
         let kubeapi = Kubeapi::local();
         ResourceArg::from_strings(&resources, &kubeapi)
@@ -390,4 +893,188 @@ mod tests {
     fn invalid_mix() {
         let _err = args(&["pod/bazooka", "node"]).unwrap_err();
     }
+
+    #[test]
+    fn built_in_without_cache() {
+        let resources = args(&["deploy,svc,sts"]).unwrap();
+        let resources = resources
+            .iter()
+            .map(ResourceArg::resource)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            resources,
+            [
+                Resource::Deployments,
+                Resource::Services,
+                Resource::StatefulSets
+            ]
+        );
+    }
+
+    #[test]
+    fn qualified_built_in() {
+        let resources = args(&["deployments.apps,deploy.v1.apps,cronjobs.batch"]).unwrap();
+        let resources = resources
+            .iter()
+            .map(ResourceArg::resource)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            resources,
+            [
+                Resource::Deployments,
+                Resource::Deployments,
+                Resource::CronJobs
+            ]
+        );
+
+        let resources = args(&["deployments.apps/web"]).unwrap();
+        assert_eq!(resources[0].resource(), &Resource::Deployments);
+        assert_eq!(resources[0].name(), Some("web"));
+
+        // Wrong group is not silently ignored
+        let _err = args(&["deployments.batch"]).unwrap_err();
+    }
+
+    #[test]
+    fn ambiguous() {
+        let arl = |group_version: &str| metav1::APIResourceList {
+            group_version: group_version.to_string(),
+            resources: vec![metav1::APIResource {
+                name: "certificates".to_string(),
+                kind: "Certificate".to_string(),
+                ..Default::default()
+            }],
+        };
+        let arls = || {
+            vec![
+                arl("cert-manager.io/v1"),
+                arl("networking.internal.knative.dev/v1alpha1"),
+            ]
+        };
+
+        let err = Resource::other(&ResourceName::parse("certificates"), arls()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "resource type \"certificates\" is ambiguous, specify one of: certificates.cert-manager.io, certificates.networking.internal.knative.dev"
+        );
+
+        let resource =
+            Resource::other(&ResourceName::parse("certificates.cert-manager.io"), arls()).unwrap();
+        let (scope, resource) = resource.api_resource();
+        assert_eq!(scope, discovery::Scope::Cluster);
+        assert_eq!(resource.api_version, "cert-manager.io/v1");
+    }
+
+    #[test]
+    fn expanded_category() {
+        let kubeapi = Kubeapi::local();
+        let expand = |text: &str| {
+            let resources = [text.to_string()];
+            let (_resources, category) = ResourceArg::expand_strings(&resources, &kubeapi).unwrap();
+            category
+        };
+        assert!(expand("all"));
+        assert!(expand("pods,all"));
+        assert!(!expand("pods,deploy"));
+        assert!(!expand("pods/foo"));
+    }
+
+    #[test]
+    fn all_without_cache() {
+        let resources = args(&["all"]).unwrap();
+        assert_eq!(resources.len(), 10);
+        assert_eq!(resources[0].resource(), &Resource::Pods);
+
+        let resources = args(&["all,ns", "foo"]).unwrap();
+        assert_eq!(resources.len(), 11);
+        assert_eq!(resources[10].resource(), &Resource::Namespaces);
+        assert_eq!(resources[10].name(), Some("foo"));
+    }
+
+    #[test]
+    fn category() {
+        let arl = |group_version: &str, name: &str, kind: &str| metav1::APIResourceList {
+            group_version: group_version.to_string(),
+            resources: vec![metav1::APIResource {
+                name: name.to_string(),
+                kind: kind.to_string(),
+                namespaced: true,
+                categories: Some(vec!["all".to_string()]),
+                ..Default::default()
+            }],
+        };
+        let arls = [
+            arl("v1", "pods", "Pod"),
+            arl("apps/v1", "deployments", "Deployment"),
+            arl("example.com/v1", "widgets", "Widget"),
+        ];
+
+        let resources = Resource::category("all", &arls).unwrap();
+        assert_eq!(resources[0], Resource::Pods);
+        assert_eq!(resources[1], Resource::Deployments);
+        assert_eq!(resources[2].to_string(), "widget");
+        assert!(Resource::category("istio-io", &arls).is_none());
+    }
+
+    #[test]
+    fn well_known_names() {
+        for (alias, resource) in Resource::WELL_KNOWN {
+            assert_eq!(
+                Resource::well_known(alias).as_ref(),
+                Some(resource),
+                "{alias}"
+            );
+            // The name a resource is printed with is accepted back
+            let name = resource.to_string();
+            assert_eq!(
+                Resource::well_known(&name).as_ref(),
+                Some(resource),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn group_mismatch() {
+        let err = args(&["deploy.batch"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the server doesn't have a resource type \"deploy.batch\""
+        );
+        let help = miette::Diagnostic::help(&err).unwrap().to_string();
+        assert_eq!(help, "\"deploy\" is in the \"apps\" group, not \"batch\"");
+
+        let err = args(&["pods.apps"]).unwrap_err();
+        let help = miette::Diagnostic::help(&err).unwrap().to_string();
+        assert_eq!(help, "\"pods\" is in the core group, not \"apps\"");
+
+        let err = args(&["deploy.v2.apps"]).unwrap_err();
+        let help = miette::Diagnostic::help(&err).unwrap().to_string();
+        assert_eq!(help, "\"deploy\" is served as \"v1\", not \"v2\"");
+    }
+
+    #[test]
+    fn unknown() {
+        let err = args(&["pdo"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the server doesn't have a resource type \"pdo\""
+        );
+        let help = miette::Diagnostic::help(&err).unwrap().to_string();
+        assert_eq!(help, "did you mean one of: pdb, po, pod?");
+    }
+
+    #[test]
+    fn built_in_api_resource() {
+        let (scope, resource) = Resource::Deployments.api_resource();
+        assert_eq!(scope, discovery::Scope::Namespaced);
+        assert_eq!(resource.api_version, "apps/v1");
+        assert_eq!(resource.plural, "deployments");
+        assert_eq!(
+            Resource::HorizontalPodAutoscalers.to_string(),
+            "horizontalpodautoscaler"
+        );
+    }
 }
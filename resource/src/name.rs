@@ -0,0 +1,183 @@
+use super::*;
+
+/// Resource type as given on the command line, `TYPE[.VERSION][.GROUP]`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ResourceName<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) version: Option<&'a str>,
+    pub(crate) group: Option<&'a str>,
+}
+
+impl<'a> ResourceName<'a> {
+    pub(crate) fn parse(text: &'a str) -> Self {
+        let Some((name, rest)) = text.split_once('.') else {
+            return Self {
+                name: text,
+                version: None,
+                group: None,
+            };
+        };
+        match rest.split_once('.') {
+            Some((version, group)) if is_version(version) => Self {
+                name,
+                version: Some(version),
+                group: Some(group),
+            },
+            _ => Self {
+                name,
+                version: None,
+                group: Some(rest),
+            },
+        }
+    }
+
+    /// Whether resources served under `group`/`version` satisfy the qualifiers
+    pub(crate) fn matches(&self, group: &str, version: &str) -> bool {
+        self.group.is_none_or(|g| g == group) && self.version.is_none_or(|v| v == version)
+    }
+
+    /// All the resources in `arls` known under this name, one per group.
+    /// Versions are listed in server preference order, so the first one wins.
+    pub(crate) fn resolve(
+        &self,
+        arls: &[metav1::APIResourceList],
+    ) -> Vec<(discovery::Scope, api::ApiResource)> {
+        let mut found = Vec::<(discovery::Scope, api::ApiResource)>::new();
+        for arl in arls {
+            let Ok(gv) = arl.group_version() else {
+                continue;
+            };
+            if !self.matches(&gv.group, &gv.version) {
+                continue;
+            }
+            let resources = arl
+                .resources
+                .iter()
+                // Subresources like `pods/log` are not addressable on their own
+                .filter(|ar| !ar.name.contains('/'))
+                .filter(|ar| ar.matches_name(self.name))
+                .map(|ar| (ar.scope(), ar.clone().kube_api_resource(gv.clone())));
+            for (scope, resource) in resources {
+                if !found.iter().any(|(_, other)| other.group == resource.group) {
+                    found.push((scope, resource));
+                }
+            }
+        }
+        found
+    }
+}
+
+impl fmt::Display for ResourceName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(f)?;
+        if let Some(version) = self.version {
+            format_args!(".{version}").fmt(f)?;
+        }
+        if let Some(group) = self.group {
+            format_args!(".{group}").fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_version(text: &str) -> bool {
+    text.strip_prefix('v')
+        .and_then(|text| text.chars().next())
+        .is_some_and(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arl(group_version: &str, resources: &[(&str, &str, &[&str])]) -> metav1::APIResourceList {
+        let resources = resources
+            .iter()
+            .map(|(name, kind, short_names)| metav1::APIResource {
+                name: name.to_string(),
+                kind: kind.to_string(),
+                namespaced: true,
+                short_names: Some(short_names.iter().map(ToString::to_string).collect()),
+                ..Default::default()
+            })
+            .collect();
+        metav1::APIResourceList {
+            group_version: group_version.to_string(),
+            resources,
+        }
+    }
+
+    fn arls() -> Vec<metav1::APIResourceList> {
+        vec![
+            arl(
+                "cert-manager.io/v1",
+                &[
+                    ("certificates", "Certificate", &["cert", "certs"]),
+                    ("certificates/status", "Certificate", &[]),
+                ],
+            ),
+            arl(
+                "networking.internal.knative.dev/v1alpha1",
+                &[("certificates", "Certificate", &["kcert"])],
+            ),
+            arl("example.com/v2", &[("widgets", "Widget", &["wd"])]),
+            arl("example.com/v1", &[("widgets", "Widget", &["wd"])]),
+        ]
+    }
+
+    #[test]
+    fn parse() {
+        let name = ResourceName::parse("deployments");
+        assert_eq!(
+            (name.name, name.version, name.group),
+            ("deployments", None, None)
+        );
+
+        let name = ResourceName::parse("deployments.apps");
+        assert_eq!(
+            (name.name, name.version, name.group),
+            ("deployments", None, Some("apps"))
+        );
+
+        let name = ResourceName::parse("deployments.v1.apps");
+        assert_eq!(
+            (name.name, name.version, name.group),
+            ("deployments", Some("v1"), Some("apps"))
+        );
+
+        let name = ResourceName::parse("certificates.cert-manager.io");
+        assert_eq!(
+            (name.name, name.version, name.group),
+            ("certificates", None, Some("cert-manager.io"))
+        );
+        assert_eq!(name.to_string(), "certificates.cert-manager.io");
+    }
+
+    #[test]
+    fn qualified() {
+        let found = ResourceName::parse("certificates.cert-manager.io").resolve(&arls());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.api_version, "cert-manager.io/v1");
+
+        let found = ResourceName::parse("kcert").resolve(&arls());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.group, "networking.internal.knative.dev");
+    }
+
+    #[test]
+    fn ambiguous() {
+        let found = ResourceName::parse("certificates").resolve(&arls());
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn preferred_version() {
+        let found = ResourceName::parse("wd").resolve(&arls());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.version, "v2");
+
+        let found = ResourceName::parse("widgets.v1.example.com").resolve(&arls());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.version, "v1");
+    }
+}
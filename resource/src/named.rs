@@ -20,18 +20,22 @@ impl NamedResource {
         &self.name
     }
 
-    pub async fn delete(&self, kubeapi: &Kubeapi, dp: &api::DeleteParams) -> kube::Result<()> {
+    pub async fn delete(
+        &self,
+        kubeapi: &Kubeapi,
+        dp: &api::DeleteParams,
+    ) -> kube::Result<Deleted<api::DynamicObject>> {
         let (scope, ref dyntype) = self.resource.api_resource();
-        kubeapi
+        // Objects deleted right away come back as Status, rebuild them from what we know
+        let k = kubeapi
             .dynamic_object_api(scope, dyntype)?
             .delete(&self.name, dp)
-            .await
-            .inspect_err(|err| eprintln!("{err:?}"))?
-            .either(
-                |object| println!("{object:?}"),
-                |status| println!("{status:?}"),
-            );
-        Ok(())
+            .await?
+            .left_or_else(|_status| api::DynamicObject::new(&self.name, dyntype));
+        Ok(Deleted {
+            k,
+            dyntype: dyntype.clone(),
+        })
     }
 }
 
@@ -0,0 +1,53 @@
+use super::*;
+
+/// Known resource type names closest to `name`, best matches first
+pub(crate) fn suggestions<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+
+    // Short names tolerate a single typo, longer ones a bit more
+    let max_distance = (name.len() / 3).max(1);
+    let mut found = candidates
+        .into_iter()
+        .map(|candidate| (strsim::osa_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect::<Vec<_>>();
+    found.sort_unstable();
+    found.dedup_by_key(|(_, candidate)| *candidate);
+    found
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// Every name the cached resources may be referred by
+pub(crate) fn cached_names(arls: &[metav1::APIResourceList]) -> impl Iterator<Item = &str> {
+    arls.iter()
+        .flat_map(|arl| &arl.resources)
+        .filter(|ar| !ar.name.contains('/'))
+        .flat_map(|ar| {
+            iter::once(ar.name.as_str())
+                .chain(iter::once(ar.singular_name.as_str()))
+                .chain(ar.short_names.iter().flatten().map(String::as_str))
+        })
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typos() {
+        let names = Resource::WELL_KNOWN.iter().map(|(alias, _)| *alias);
+        assert_eq!(suggestions("pdo", names.clone()), ["pdb", "po", "pod"]);
+        assert_eq!(
+            suggestions("deploymnets", names.clone()),
+            ["deployments", "deployment"]
+        );
+        assert!(suggestions("zzz", names).is_empty());
+    }
+}
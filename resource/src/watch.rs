@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use futures_util::StreamExt;
+use futures_util::stream::LocalBoxStream;
+use kube::runtime::WatchStreamExt;
+use kube::runtime::watcher;
+
+use rkubectl_ui::WatchEvent;
+use rkubectl_ui::WatchEventType;
+
+use super::*;
+
+pub type WatchStream = LocalBoxStream<'static, Result<Vec<WatchEvent>, watcher::Error>>;
+
+impl ResourceArg {
+    /// Watch the resource for changes.
+    /// Initial listing is reported as a single batch of `ADDED` events unless `watch_only` is set.
+    /// Watch is resumed from the last seen resourceVersion when the connection drops.
+    pub fn watch(&self, kubeapi: &Kubeapi, watch_only: bool) -> kube::Result<WatchStream> {
+        let config = kubeapi.watcher_config();
+        let config = if let Some(name) = self.name() {
+            let name = format!("metadata.name={name}");
+            let fields = config
+                .field_selector
+                .as_ref()
+                .map_or(name.clone(), |fields| format!("{fields},{name}"));
+            config.fields(&fields)
+        } else {
+            config
+        };
+        self.resource().watch(kubeapi, config, watch_only)
+    }
+}
+
+impl Resource {
+    fn watch(
+        &self,
+        kubeapi: &Kubeapi,
+        config: watcher::Config,
+        watch_only: bool,
+    ) -> kube::Result<WatchStream> {
+        let stream = match self {
+            Self::Pods => watch(kubeapi.pods()?, config, watch_only),
+            Self::Services => watch(kubeapi.services()?, config, watch_only),
+            Self::Namespaces => watch(kubeapi.namespaces()?, config, watch_only),
+            Self::Nodes => watch(kubeapi.nodes()?, config, watch_only),
+            Self::ConfigMaps => watch(kubeapi.configmaps()?, config, watch_only),
+            Self::Secrets => watch(kubeapi.secrets()?, config, watch_only),
+            Self::ServiceAccounts => watch(kubeapi.serviceaccounts()?, config, watch_only),
+            Self::Endpoints => watch(kubeapi.endpoints()?, config, watch_only),
+            Self::Events => watch(kubeapi.events()?, config, watch_only),
+            Self::PersistentVolumes => watch(kubeapi.persistentvolumes()?, config, watch_only),
+            Self::PersistentVolumeClaims => {
+                watch(kubeapi.persistentvolumeclaims()?, config, watch_only)
+            }
+            Self::ReplicationControllers => {
+                watch(kubeapi.replicationcontrollers()?, config, watch_only)
+            }
+            Self::ResourceQuotas => watch(kubeapi.resourcequotas()?, config, watch_only),
+            Self::LimitRanges => watch(kubeapi.limitranges()?, config, watch_only),
+            Self::ComponentStatuses => watch(kubeapi.componentstatuses()?, config, watch_only),
+            Self::Deployments => watch(kubeapi.deployments()?, config, watch_only),
+            Self::ReplicaSets => watch(kubeapi.replicasets()?, config, watch_only),
+            Self::StatefulSets => watch(kubeapi.statefulsets()?, config, watch_only),
+            Self::DaemonSets => watch(kubeapi.daemonsets()?, config, watch_only),
+            Self::ControllerRevisions => watch(kubeapi.controllerrevisions()?, config, watch_only),
+            Self::Jobs => watch(kubeapi.jobs()?, config, watch_only),
+            Self::CronJobs => watch(kubeapi.cronjobs()?, config, watch_only),
+            Self::Ingresses => watch(kubeapi.ingresses()?, config, watch_only),
+            Self::IngressClasses => watch(kubeapi.ingressclasses()?, config, watch_only),
+            Self::NetworkPolicies => watch(kubeapi.networkpolicies()?, config, watch_only),
+            Self::Roles => watch(kubeapi.roles()?, config, watch_only),
+            Self::RoleBindings => watch(kubeapi.rolebindings()?, config, watch_only),
+            Self::ClusterRoles => watch(kubeapi.clusterroles()?, config, watch_only),
+            Self::ClusterRoleBindings => watch(kubeapi.clusterrolebindings()?, config, watch_only),
+            Self::PodDisruptionBudgets => {
+                watch(kubeapi.poddisruptionbudgets()?, config, watch_only)
+            }
+            Self::StorageClasses => watch(kubeapi.storageclasses()?, config, watch_only),
+            Self::CsiDrivers => watch(kubeapi.csidrivers()?, config, watch_only),
+            Self::CsiNodes => watch(kubeapi.csinodes()?, config, watch_only),
+            Self::VolumeAttachments => watch(kubeapi.volumeattachments()?, config, watch_only),
+            Self::HorizontalPodAutoscalers => {
+                watch(kubeapi.horizontalpodautoscalers()?, config, watch_only)
+            }
+            Self::Other { scope, resource } => {
+                let types = Self::types(resource);
+                let api = kubeapi.dynamic_object_api(scope.clone(), resource)?;
+                let mut tracker = Tracker::new(watch_only);
+                watcher::watcher(api, config)
+                    .default_backoff()
+                    .map(move |event| {
+                        event
+                            .map(|event| {
+                                event.modify(|object| {
+                                    object.types.get_or_insert_with(|| types.clone());
+                                })
+                            })
+                            .map(|event| tracker.track(event))
+                    })
+                    .boxed_local()
+            }
+        };
+        Ok(stream)
+    }
+}
+
+fn watch<K>(api: api::Api<K>, config: watcher::Config, watch_only: bool) -> WatchStream
+where
+    K: Clone + fmt::Debug + Show + kube::Resource + serde::de::DeserializeOwned + Send + 'static,
+{
+    let mut tracker = Tracker::new(watch_only);
+    watcher::watcher(api, config)
+        .default_backoff()
+        .map(move |event| event.map(|event| tracker.track(event)))
+        .boxed_local()
+}
+
+/// Turns watcher events into ADDED/MODIFIED/DELETED events
+/// by remembering the objects seen so far.
+struct Tracker<K> {
+    watch_only: bool,
+    initial: bool,
+    seen: HashMap<String, K>,
+    listing: Option<(Vec<WatchEvent>, HashSet<String>)>,
+}
+
+impl<K> Tracker<K>
+where
+    K: Clone + Show + kube::Resource + 'static,
+{
+    fn new(watch_only: bool) -> Self {
+        Self {
+            watch_only,
+            initial: true,
+            seen: HashMap::new(),
+            listing: None,
+        }
+    }
+
+    fn track(&mut self, event: watcher::Event<K>) -> Vec<WatchEvent> {
+        match event {
+            watcher::Event::Init => {
+                self.listing = Some((vec![], HashSet::new()));
+                vec![]
+            }
+            watcher::Event::InitApply(object) => {
+                let key = key(&object);
+                let event = self.apply(key.clone(), object);
+                if let Some((events, listed)) = &mut self.listing {
+                    listed.insert(key);
+                    let initial = self.initial && self.watch_only;
+                    events.extend(event.filter(|_| !initial));
+                }
+                vec![]
+            }
+            watcher::Event::InitDone => {
+                self.initial = false;
+                let (mut events, listed) = self.listing.take().unwrap_or_default();
+                // Objects not seen in the new listing were deleted while disconnected
+                let gone = self
+                    .seen
+                    .keys()
+                    .filter(|key| !listed.contains(*key))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                events.extend(
+                    gone.into_iter()
+                        .filter_map(|key| self.seen.remove(&key))
+                        .map(|object| watch_event(WatchEventType::Deleted, object)),
+                );
+                events
+            }
+            watcher::Event::Apply(object) => self.apply(key(&object), object).into_iter().collect(),
+            watcher::Event::Delete(object) => {
+                self.seen.remove(&key(&object));
+                vec![watch_event(WatchEventType::Deleted, object)]
+            }
+        }
+    }
+
+    fn apply(&mut self, key: String, object: K) -> Option<WatchEvent> {
+        let r#type = match self.seen.get(&key) {
+            None => Some(WatchEventType::Added),
+            Some(seen) if seen.meta().resource_version != object.meta().resource_version => {
+                Some(WatchEventType::Modified)
+            }
+            Some(_) => None,
+        };
+        self.seen.insert(key, object.clone());
+        r#type.map(|r#type| watch_event(r#type, object))
+    }
+}
+
+fn key<K: kube::Resource>(object: &K) -> String {
+    let namespace = object.namespace().unwrap_or_default();
+    let name = object.name_any();
+    format!("{namespace}/{name}")
+}
+
+fn watch_event<K: Show + 'static>(r#type: WatchEventType, object: K) -> WatchEvent {
+    let object = Box::new(object);
+    WatchEvent { r#type, object }
+}
@@ -1,4 +1,3 @@
-use miette::IntoDiagnostic;
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
 use rkubectl_app as app;
@@ -10,7 +9,7 @@ async fn main() -> miette::Result<()> {
         .with(EnvFilter::from_default_env())
         .init();
 
-    app::Cli::new().exec().await.into_diagnostic()?;
+    app::Cli::new().exec().await?;
 
     Ok(())
 }
@@ -0,0 +1,194 @@
+use super::*;
+
+/// Columns for `-o custom-columns=NAME:.metadata.name,NODE:.spec.nodeName`
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomColumns {
+    columns: Vec<CustomColumn>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct CustomColumn {
+    header: String,
+    jsonpath: JsonPath,
+}
+
+impl CustomColumns {
+    /// Parse the file format, a line of headers followed by a line of expressions
+    pub fn from_template(template: &str) -> Result<Self, CustomColumnsError> {
+        let mut lines = template.lines().filter(|line| !line.trim().is_empty());
+        let headers = lines.next().unwrap_or_default().split_whitespace();
+        let expressions = lines
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        let headers = headers.collect::<Vec<_>>();
+
+        if headers.len() != expressions.len() {
+            return Err(CustomColumnsError::ColumnCountMismatch(
+                headers.len(),
+                expressions.len(),
+            ));
+        }
+
+        headers
+            .into_iter()
+            .zip(expressions)
+            .map(|(header, expression)| CustomColumn::new(header, expression))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(Self::new)
+    }
+
+    pub fn header(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.header.clone())
+            .collect()
+    }
+
+    /// One row per object, or one row per item when `value` is a list,
+    /// either a `List` or a typed one such as `PodList`
+    pub fn rows(&self, value: &json::Value) -> Vec<Vec<String>> {
+        let items = match value.get("items").and_then(json::Value::as_array) {
+            Some(items) => items.iter().collect(),
+            None => vec![value],
+        };
+
+        items.into_iter().map(|item| self.row(item)).collect()
+    }
+
+    fn new(columns: Vec<CustomColumn>) -> Result<Self, CustomColumnsError> {
+        if columns.is_empty() {
+            Err(CustomColumnsError::Empty)
+        } else {
+            Ok(Self { columns })
+        }
+    }
+
+    fn row(&self, item: &json::Value) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| {
+                let values = column.jsonpath.values(item);
+                if values.is_empty() {
+                    "<none>".to_string()
+                } else {
+                    values.join(",")
+                }
+            })
+            .collect()
+    }
+}
+
+impl CustomColumn {
+    fn new(header: &str, expression: &str) -> Result<Self, CustomColumnsError> {
+        let header = header.to_string();
+        let jsonpath = JsonPath::relaxed(expression)?;
+        Ok(Self { header, jsonpath })
+    }
+}
+
+impl str::FromStr for CustomColumns {
+    type Err = CustomColumnsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|spec| {
+                spec.split_once(':')
+                    .ok_or_else(|| CustomColumnsError::InvalidSpec(spec.to_string()))
+                    .and_then(|(header, expression)| CustomColumn::new(header, expression))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(Self::new)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CustomColumnsError {
+    #[error("custom-columns format specified but no custom columns given")]
+    Empty,
+    #[error("unexpected custom-columns spec: {0}, expected <header>:<json-path-expr>")]
+    InvalidSpec(String),
+    #[error("number of headers ({0}) doesn't match number of columns ({1})")]
+    ColumnCountMismatch(usize, usize),
+    #[error(transparent)]
+    JsonPath(#[from] JsonPathError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pods() -> json::Value {
+        json::json!({
+            "kind": "List",
+            "items": [
+                {"metadata": {"name": "alpha"}, "spec": {"nodeName": "node-1", "containers": [{"name": "a"}, {"name": "b"}]}},
+                {"metadata": {"name": "beta"}, "spec": {"containers": [{"name": "c"}]}}
+            ]
+        })
+    }
+
+    #[test]
+    fn spec() {
+        let columns =
+            "NAME:.metadata.name,NODE:{.spec.nodeName},CONTAINERS:spec.containers[*].name"
+                .parse::<CustomColumns>()
+                .unwrap();
+        assert_eq!(columns.header(), ["NAME", "NODE", "CONTAINERS"]);
+        assert_eq!(
+            columns.rows(&pods()),
+            [["alpha", "node-1", "a,b"], ["beta", "<none>", "c"]]
+        );
+    }
+
+    #[test]
+    fn single_object() {
+        let columns = "NAME:.metadata.name".parse::<CustomColumns>().unwrap();
+        let pod = json::json!({"metadata": {"name": "alpha"}});
+        assert_eq!(columns.rows(&pod), [["alpha"]]);
+    }
+
+    #[test]
+    fn typed_list() {
+        let pod = |name: &str| corev1::Pod {
+            metadata: metav1::ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let list = kube::core::ObjectList {
+            types: kube::core::TypeMeta {
+                api_version: "v1".to_string(),
+                kind: "PodList".to_string(),
+            },
+            metadata: Default::default(),
+            items: vec![pod("alpha"), pod("beta")],
+        };
+        let value = json::to_value(list).unwrap();
+
+        let columns = "NAME:.metadata.name".parse::<CustomColumns>().unwrap();
+        assert_eq!(columns.rows(&value), [["alpha"], ["beta"]]);
+    }
+
+    #[test]
+    fn template() {
+        let template = "NAME          NODE\n.metadata.name   .spec.nodeName\n";
+        let columns = CustomColumns::from_template(template).unwrap();
+        assert_eq!(columns.header(), ["NAME", "NODE"]);
+
+        assert!(matches!(
+            CustomColumns::from_template("NAME NODE\n.metadata.name"),
+            Err(CustomColumnsError::ColumnCountMismatch(2, 1))
+        ));
+    }
+
+    #[test]
+    fn invalid_spec() {
+        assert!(matches!(
+            "NAME".parse::<CustomColumns>(),
+            Err(CustomColumnsError::InvalidSpec(_))
+        ));
+    }
+}
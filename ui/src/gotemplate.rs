@@ -0,0 +1,107 @@
+use std::str;
+
+use super::*;
+
+mod exec;
+mod funcs;
+mod lexer;
+mod parser;
+
+/// Go `text/template` compatible template as used by `-o go-template`,
+/// e.g. `{{range .items}}{{.metadata.name}}{{"\n"}}{{end}}`
+#[derive(Clone, Debug)]
+pub struct GoTemplate {
+    template: String,
+    nodes: Vec<Node>,
+}
+
+impl GoTemplate {
+    pub fn new(template: impl ToString) -> Result<Self, GoTemplateError> {
+        let template = template.to_string();
+        let nodes = parser::parse(&template)?;
+        Ok(Self { template, nodes })
+    }
+
+    pub fn render(&self, value: &json::Value) -> Result<String, GoTemplateError> {
+        exec::execute(&self.nodes, value)
+    }
+}
+
+impl PartialEq for GoTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        self.template == other.template
+    }
+}
+
+impl fmt::Display for GoTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.template.fmt(f)
+    }
+}
+
+impl str::FromStr for GoTemplate {
+    type Err = GoTemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum GoTemplateError {
+    #[error("template: output: {0}")]
+    Parse(String),
+    #[error("template: output: executing \"output\": {0}")]
+    Exec(String),
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Text(String),
+    Action(Pipeline),
+    Control(Control, Pipeline, Vec<Self>, Vec<Self>),
+    Break,
+    Continue,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Control {
+    If,
+    Range,
+    With,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Pipeline {
+    /// `$x :=` declares, `$x =` assigns
+    variables: Vec<String>,
+    declare: bool,
+    commands: Vec<Command>,
+}
+
+#[derive(Clone, Debug)]
+struct Command {
+    args: Vec<Arg>,
+}
+
+#[derive(Clone, Debug)]
+enum Arg {
+    /// `.`
+    Dot,
+    /// `.metadata.name`
+    Field(Vec<String>),
+    /// `$` or `$x.metadata.name`
+    Variable(String, Vec<String>),
+    /// `printf`
+    Function(String),
+    /// `"text"`, `1`, `true` or `nil`
+    Literal(json::Value),
+    /// `(index .items 0).metadata.name`
+    Pipeline(Box<Pipeline>, Vec<String>),
+}
+
+/// Missing map keys are `None` and print as `<no value>`
+type Value = Option<json::Value>;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,214 @@
+use super::*;
+
+pub(super) fn execute(nodes: &[Node], root: &json::Value) -> Result<String, GoTemplateError> {
+    let root = Some(root.clone());
+    let mut state = State {
+        output: String::new(),
+        variables: vec![("$".to_string(), root.clone())],
+    };
+    state.list(nodes, &root).map_err(GoTemplateError::Exec)?;
+    Ok(state.output)
+}
+
+enum Flow {
+    Next,
+    Break,
+    Continue,
+}
+
+struct State {
+    output: String,
+    variables: Vec<(String, Value)>,
+}
+
+impl State {
+    fn list(&mut self, nodes: &[Node], dot: &Value) -> Result<Flow, String> {
+        for node in nodes {
+            let flow = match node {
+                Node::Text(text) => {
+                    self.output.push_str(text);
+                    Flow::Next
+                }
+                Node::Action(pipeline) => {
+                    let value = self.pipeline(pipeline, dot)?;
+                    if pipeline.variables.is_empty() {
+                        self.output.push_str(&funcs::print(&value));
+                    }
+                    Flow::Next
+                }
+                Node::Control(control, pipeline, body, otherwise) => {
+                    let mark = self.variables.len();
+                    let flow = self.control(*control, pipeline, body, otherwise, dot);
+                    self.variables.truncate(mark);
+                    flow?
+                }
+                Node::Break => Flow::Break,
+                Node::Continue => Flow::Continue,
+            };
+
+            if !matches!(flow, Flow::Next) {
+                return Ok(flow);
+            }
+        }
+
+        Ok(Flow::Next)
+    }
+
+    fn control(
+        &mut self,
+        control: Control,
+        pipeline: &Pipeline,
+        body: &[Node],
+        otherwise: &[Node],
+        dot: &Value,
+    ) -> Result<Flow, String> {
+        match control {
+            Control::If => {
+                let value = self.pipeline(pipeline, dot)?;
+                if funcs::truth(&value) {
+                    self.list(body, dot)
+                } else {
+                    self.list(otherwise, dot)
+                }
+            }
+            Control::With => {
+                let value = self.pipeline(pipeline, dot)?;
+                if funcs::truth(&value) {
+                    self.list(body, &value)
+                } else {
+                    self.list(otherwise, dot)
+                }
+            }
+            Control::Range => {
+                let value = self.commands(pipeline, dot)?;
+                let items = iterate(&value)?;
+                if items.is_empty() {
+                    return self.list(otherwise, dot);
+                }
+
+                let mark = self.variables.len();
+                for (key, item) in items {
+                    self.variables.truncate(mark);
+                    match pipeline.variables.as_slice() {
+                        [value] => self.variables.push((value.clone(), item.clone())),
+                        [key_name, value] => {
+                            self.variables.push((key_name.clone(), key));
+                            self.variables.push((value.clone(), item.clone()));
+                        }
+                        _ => {}
+                    }
+                    if let Flow::Break = self.list(body, &item)? {
+                        break;
+                    }
+                }
+                Ok(Flow::Next)
+            }
+        }
+    }
+
+    fn pipeline(&mut self, pipeline: &Pipeline, dot: &Value) -> Result<Value, String> {
+        let value = self.commands(pipeline, dot)?;
+        for name in &pipeline.variables {
+            if pipeline.declare {
+                self.variables.push((name.clone(), value.clone()));
+            } else {
+                let variable = self
+                    .variables
+                    .iter_mut()
+                    .rev()
+                    .find(|(variable, _)| variable == name)
+                    .ok_or_else(|| format!("undefined variable: {name}"))?;
+                variable.1 = value.clone();
+            }
+        }
+        Ok(value)
+    }
+
+    fn commands(&mut self, pipeline: &Pipeline, dot: &Value) -> Result<Value, String> {
+        let mut piped = None;
+        for command in &pipeline.commands {
+            piped = Some(self.command(command, dot, piped)?);
+        }
+        Ok(piped.flatten())
+    }
+
+    fn command(
+        &mut self,
+        command: &Command,
+        dot: &Value,
+        piped: Option<Value>,
+    ) -> Result<Value, String> {
+        match command.args.as_slice() {
+            [Arg::Function(name), args @ ..] => {
+                let mut values = args
+                    .iter()
+                    .map(|arg| self.arg(arg, dot))
+                    .collect::<Result<Vec<_>, _>>()?;
+                values.extend(piped);
+                funcs::call(name, values)
+            }
+            [arg] if piped.is_none() => self.arg(arg, dot),
+            _ => Err("can't give argument to non-function".to_string()),
+        }
+    }
+
+    fn arg(&mut self, arg: &Arg, dot: &Value) -> Result<Value, String> {
+        match arg {
+            Arg::Dot => Ok(dot.clone()),
+            Arg::Field(chain) => fields(dot.clone(), chain),
+            Arg::Variable(name, chain) => {
+                let value = self
+                    .variables
+                    .iter()
+                    .rev()
+                    .find(|(variable, _)| variable == name)
+                    .map(|(_, value)| value.clone())
+                    .ok_or_else(|| format!("undefined variable: {name}"))?;
+                fields(value, chain)
+            }
+            Arg::Function(name) => funcs::call(name, vec![]),
+            Arg::Literal(value) => Ok(Some(value.clone())),
+            Arg::Pipeline(pipeline, chain) => {
+                let value = self.pipeline(pipeline, dot)?;
+                fields(value, chain)
+            }
+        }
+    }
+}
+
+fn fields(value: Value, chain: &[String]) -> Result<Value, String> {
+    chain.iter().try_fold(value, |value, name| match value {
+        None => Ok(None),
+        Some(json::Value::Object(mut map)) => Ok(map.remove(name)),
+        Some(json::Value::Null) => Err(format!("nil pointer evaluating interface {{}}.{name}")),
+        Some(other) => Err(format!(
+            "can't evaluate field {name} in type {}",
+            funcs::type_name(&other)
+        )),
+    })
+}
+
+// Range yields index or key together with the element
+fn iterate(value: &Value) -> Result<Vec<(Value, Value)>, String> {
+    match value {
+        None | Some(json::Value::Null) => Ok(vec![]),
+        Some(json::Value::Array(items)) => Ok(items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| (Some(json::Value::from(idx)), Some(item.clone())))
+            .collect()),
+        Some(json::Value::Object(map)) => Ok(map
+            .iter()
+            .map(|(key, item)| (Some(json::Value::from(key.as_str())), Some(item.clone())))
+            .collect()),
+        Some(json::Value::Number(n)) if n.as_u64().is_some() => {
+            Ok((0..n.as_u64().unwrap_or_default())
+                .map(|idx| (Some(json::Value::from(idx)), Some(json::Value::from(idx))))
+                .collect())
+        }
+        Some(other) => Err(format!(
+            "range can't iterate over {}",
+            funcs::print(&Some(other.clone()))
+        )),
+    }
+}
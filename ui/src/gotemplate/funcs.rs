@@ -0,0 +1,480 @@
+use std::cmp::Ordering;
+
+use rkubectl_ext::Base64Decode;
+
+use super::*;
+
+const FUNCTIONS: &[&str] = &[
+    "and",
+    "base64decode",
+    "eq",
+    "exists",
+    "ge",
+    "gt",
+    "html",
+    "index",
+    "js",
+    "le",
+    "len",
+    "lt",
+    "ne",
+    "not",
+    "or",
+    "print",
+    "printf",
+    "println",
+    "slice",
+    "urlquery",
+];
+
+pub(super) fn exists(name: &str) -> bool {
+    FUNCTIONS.contains(&name)
+}
+
+pub(super) fn call(name: &str, args: Vec<Value>) -> Result<Value, String> {
+    let value = match (name, args.as_slice()) {
+        ("and", [.., last]) => args.iter().find(|arg| !truth(arg)).unwrap_or(last).clone(),
+        ("or", [.., last]) => args.iter().find(|arg| truth(arg)).unwrap_or(last).clone(),
+        ("not", [arg]) => Some(json::Value::Bool(!truth(arg))),
+        ("len", [arg]) => Some(json::Value::from(len(arg)?)),
+        ("index", [item, indices @ ..]) => indices.iter().try_fold(item.clone(), index)?,
+        ("slice", [item, indices @ ..]) if indices.len() <= 3 => slice(item, indices)?,
+        ("print", _) => Some(json::Value::String(sprint(&args))),
+        ("println", _) => Some(json::Value::String(sprintln(&args))),
+        ("printf", [format, args @ ..]) => Some(json::Value::String(sprintf(&print(format), args))),
+        ("eq", [left, rights @ ..]) if !rights.is_empty() => Some(json::Value::Bool(
+            rights.iter().any(|right| equal(left, right)),
+        )),
+        ("ne", [left, right]) => Some(json::Value::Bool(!equal(left, right))),
+        ("lt", [left, right]) => ordered(left, right, Ordering::is_lt)?,
+        ("le", [left, right]) => ordered(left, right, Ordering::is_le)?,
+        ("gt", [left, right]) => ordered(left, right, Ordering::is_gt)?,
+        ("ge", [left, right]) => ordered(left, right, Ordering::is_ge)?,
+        ("html", _) => Some(json::Value::String(html(&sprint(&args)))),
+        ("js", _) => Some(json::Value::String(js(&sprint(&args)))),
+        ("urlquery", _) => Some(json::Value::String(urlquery(&sprint(&args)))),
+        ("base64decode", [Some(json::Value::String(text))]) => text
+            .as_str()
+            .decode()
+            .map(json::Value::String)
+            .map(Some)
+            .map_err(|err| format!("error calling base64decode: base64 decode failed: {err}"))?,
+        ("base64decode", [other]) => {
+            return Err(format!(
+                "error calling base64decode: expected string, got {}",
+                value_type(other)
+            ));
+        }
+        ("exists", [item, indices @ ..]) => Some(json::Value::Bool(
+            indices
+                .iter()
+                .try_fold(item.clone(), index)
+                .is_ok_and(|value| value.is_some()),
+        )),
+        _ => {
+            return Err(format!(
+                "wrong number of args for {name}: got {}",
+                args.len()
+            ));
+        }
+    };
+
+    Ok(value)
+}
+
+pub(super) fn truth(value: &Value) -> bool {
+    match value {
+        None | Some(json::Value::Null) => false,
+        Some(json::Value::Bool(b)) => *b,
+        Some(json::Value::Number(n)) => n.as_f64().is_some_and(|n| n != 0.0),
+        Some(json::Value::String(text)) => !text.is_empty(),
+        Some(json::Value::Array(items)) => !items.is_empty(),
+        Some(json::Value::Object(map)) => !map.is_empty(),
+    }
+}
+
+/// Go `%v` formatting of a value
+pub(super) fn print(value: &Value) -> String {
+    match value {
+        None => "<no value>".to_string(),
+        Some(value) => print_json(value),
+    }
+}
+
+fn print_json(value: &json::Value) -> String {
+    match value {
+        json::Value::Null => "<nil>".to_string(),
+        json::Value::Bool(b) => b.to_string(),
+        json::Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() => f.to_string(),
+            _ => n.to_string(),
+        },
+        json::Value::String(text) => text.clone(),
+        json::Value::Array(items) => {
+            let items = items.iter().map(print_json).collect::<Vec<_>>();
+            format!("[{}]", items.join(" "))
+        }
+        json::Value::Object(map) => {
+            let entries = map
+                .iter()
+                .map(|(key, value)| format!("{key}:{}", print_json(value)))
+                .collect::<Vec<_>>();
+            format!("map[{}]", entries.join(" "))
+        }
+    }
+}
+
+/// Go type name as seen by kubectl after decoding JSON into `interface{}`
+pub(super) fn type_name(value: &json::Value) -> &'static str {
+    match value {
+        json::Value::Null => "<nil>",
+        json::Value::Bool(_) => "bool",
+        json::Value::Number(_) => "float64",
+        json::Value::String(_) => "string",
+        json::Value::Array(_) => "[]interface {}",
+        json::Value::Object(_) => "map[string]interface {}",
+    }
+}
+
+fn value_type(value: &Value) -> &'static str {
+    value.as_ref().map_or("<nil>", type_name)
+}
+
+fn len(value: &Value) -> Result<usize, String> {
+    match value {
+        Some(json::Value::String(text)) => Ok(text.len()),
+        Some(json::Value::Array(items)) => Ok(items.len()),
+        Some(json::Value::Object(map)) => Ok(map.len()),
+        other => Err(format!(
+            "error calling len: len of type {}",
+            value_type(other)
+        )),
+    }
+}
+
+fn index(item: Value, key: &Value) -> Result<Value, String> {
+    match (item, key) {
+        (Some(json::Value::Array(mut items)), Some(json::Value::Number(n))) => {
+            let len = items.len();
+            n.as_u64()
+                .and_then(|idx| usize::try_from(idx).ok())
+                .filter(|idx| *idx < len)
+                .map(|idx| Some(items.swap_remove(idx)))
+                .ok_or_else(|| format!("error calling index: index out of range: {n}"))
+        }
+        (Some(json::Value::Object(mut map)), Some(json::Value::String(key))) => Ok(map.remove(key)),
+        (None | Some(json::Value::Null), _) => {
+            Err("error calling index: index of untyped nil".to_string())
+        }
+        (Some(item), key) => Err(format!(
+            "error calling index: cannot index {} with {}",
+            type_name(&item),
+            value_type(key)
+        )),
+    }
+}
+
+fn slice(item: &Value, indices: &[Value]) -> Result<Value, String> {
+    let indices = indices
+        .iter()
+        .map(|idx| {
+            idx.as_ref()
+                .and_then(json::Value::as_u64)
+                .and_then(|idx| usize::try_from(idx).ok())
+                .ok_or_else(|| "error calling slice: invalid slice index".to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let bounds = |len: usize| {
+        let start = indices.first().copied().unwrap_or(0);
+        let end = indices.get(1).copied().unwrap_or(len);
+        if start <= end && end <= len {
+            Ok((start, end))
+        } else {
+            Err(format!("error calling slice: index out of range: {end}"))
+        }
+    };
+
+    match item {
+        Some(json::Value::String(text)) if indices.len() < 3 => {
+            let (start, end) = bounds(text.len())?;
+            text.get(start..end)
+                .map(|text| Some(json::Value::from(text)))
+                .ok_or_else(|| "error calling slice: invalid slice index".to_string())
+        }
+        Some(json::Value::Array(items)) => {
+            let (start, end) = bounds(items.len())?;
+            Ok(Some(json::Value::Array(items[start..end].to_vec())))
+        }
+        other => Err(format!(
+            "error calling slice: can't slice item of type {}",
+            value_type(other)
+        )),
+    }
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Some(json::Value::Number(left)), Some(json::Value::Number(right))) => left
+            .as_f64()
+            .zip(right.as_f64())
+            .and_then(|(left, right)| left.partial_cmp(&right)),
+        (Some(json::Value::String(left)), Some(json::Value::String(right))) => {
+            Some(left.cmp(right))
+        }
+        _ => None,
+    }
+}
+
+fn equal(left: &Value, right: &Value) -> bool {
+    match compare(left, right) {
+        Some(ordering) => ordering.is_eq(),
+        None => {
+            left.as_ref().unwrap_or(&json::Value::Null)
+                == right.as_ref().unwrap_or(&json::Value::Null)
+        }
+    }
+}
+
+fn ordered(left: &Value, right: &Value, f: fn(Ordering) -> bool) -> Result<Value, String> {
+    compare(left, right)
+        .map(|ordering| Some(json::Value::Bool(f(ordering))))
+        .ok_or_else(|| "incompatible types for comparison".to_string())
+}
+
+fn is_string(value: &Value) -> bool {
+    matches!(value, Some(json::Value::String(_)))
+}
+
+/// Go `fmt.Sprint`, spaces are added between operands when neither is a string
+fn sprint(args: &[Value]) -> String {
+    let mut text = String::new();
+    for (idx, arg) in args.iter().enumerate() {
+        if idx > 0 && !is_string(arg) && !is_string(&args[idx - 1]) {
+            text.push(' ');
+        }
+        text.push_str(&print(arg));
+    }
+    text
+}
+
+/// Go `fmt.Sprintln`
+fn sprintln(args: &[Value]) -> String {
+    let mut text = args.iter().map(print).collect::<Vec<_>>().join(" ");
+    text.push('\n');
+    text
+}
+
+/// Go `fmt.Sprintf` for the commonly used verbs
+fn sprintf(format: &str, args: &[Value]) -> String {
+    let mut output = String::new();
+    let mut args = args.iter();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+
+        let mut spec = Spec::default();
+        while let Some(flag) = chars.next_if(|c| matches!(c, '-' | '+' | ' ' | '0' | '#')) {
+            match flag {
+                '-' => spec.left = true,
+                '+' => spec.plus = true,
+                '0' => spec.zero = true,
+                _ => {}
+            }
+        }
+        spec.width = digits(&mut chars);
+        if chars.next_if_eq(&'.').is_some() {
+            spec.precision = Some(digits(&mut chars).unwrap_or(0));
+        }
+
+        let Some(verb) = chars.next() else {
+            output.push_str("%!(NOVERB)");
+            break;
+        };
+        if verb == '%' {
+            output.push('%');
+            continue;
+        }
+
+        let Some(arg) = args.next() else {
+            output.push_str(&format!("%!{verb}(MISSING)"));
+            continue;
+        };
+        output.push_str(&spec.format(verb, arg));
+    }
+
+    let extra = args
+        .map(|arg| format!("{}={}", value_type(arg), print(arg)))
+        .collect::<Vec<_>>();
+    if !extra.is_empty() {
+        output.push_str(&format!("%!(EXTRA {})", extra.join(", ")));
+    }
+
+    output
+}
+
+fn digits(chars: &mut iter::Peekable<str::Chars<'_>>) -> Option<usize> {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits.parse().ok()
+}
+
+#[derive(Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    zero: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    fn format(&self, verb: char, arg: &Value) -> String {
+        let bad = || format!("%!{verb}({}={})", value_type(arg), print(arg));
+        let number = arg.as_ref().and_then(json::Value::as_f64);
+        let integer = arg
+            .as_ref()
+            .and_then(json::Value::as_i64)
+            .or_else(|| number.filter(|n| n.fract() == 0.0).map(|n| n as i64));
+
+        let text = match verb {
+            'v' => print(arg),
+            's' => {
+                let text = print(arg);
+                match self.precision {
+                    Some(precision) => text.chars().take(precision).collect(),
+                    None => text,
+                }
+            }
+            'q' => match arg {
+                Some(json::Value::String(text)) => json::to_string(text).unwrap_or_default(),
+                _ => bad(),
+            },
+            't' => match arg {
+                Some(json::Value::Bool(b)) => b.to_string(),
+                _ => bad(),
+            },
+            'd' => integer.map_or_else(bad, |n| self.sign(n.to_string(), n >= 0)),
+            'x' => match arg {
+                Some(json::Value::String(text)) => {
+                    text.bytes().map(|b| format!("{b:02x}")).collect()
+                }
+                _ => integer.map_or_else(bad, |n| format!("{n:x}")),
+            },
+            'X' => match arg {
+                Some(json::Value::String(text)) => {
+                    text.bytes().map(|b| format!("{b:02X}")).collect()
+                }
+                _ => integer.map_or_else(bad, |n| format!("{n:X}")),
+            },
+            'o' => integer.map_or_else(bad, |n| format!("{n:o}")),
+            'b' => integer.map_or_else(bad, |n| format!("{n:b}")),
+            'c' => integer
+                .and_then(|n| u32::try_from(n).ok())
+                .and_then(char::from_u32)
+                .map_or_else(bad, String::from),
+            'f' | 'F' => number.map_or_else(bad, |n| {
+                let precision = self.precision.unwrap_or(6);
+                self.sign(format!("{n:.precision$}"), n >= 0.0)
+            }),
+            'e' => number.map_or_else(bad, |n| {
+                let precision = self.precision.unwrap_or(6);
+                self.sign(exponent(format!("{n:.precision$e}")), n >= 0.0)
+            }),
+            'g' => number.map_or_else(bad, |n| self.sign(n.to_string(), n >= 0.0)),
+            _ => bad(),
+        };
+
+        self.pad(text, number.is_some() && verb != 's' && verb != 'v')
+    }
+
+    fn sign(&self, text: String, positive: bool) -> String {
+        if self.plus && positive {
+            format!("+{text}")
+        } else {
+            text
+        }
+    }
+
+    fn pad(&self, text: String, numeric: bool) -> String {
+        let width = self.width.unwrap_or(0);
+        let len = text.chars().count();
+        if len >= width {
+            return text;
+        }
+
+        let fill = width - len;
+        if self.left {
+            format!("{text}{}", " ".repeat(fill))
+        } else if self.zero && numeric {
+            match text.strip_prefix(['-', '+']) {
+                Some(digits) => format!("{}{}{digits}", &text[..1], "0".repeat(fill)),
+                None => format!("{}{text}", "0".repeat(fill)),
+            }
+        } else {
+            format!("{}{text}", " ".repeat(fill))
+        }
+    }
+}
+
+// Go always prints at least two exponent digits with a sign, e.g. `1.5e+00`
+fn exponent(text: String) -> String {
+    match text.split_once('e') {
+        Some((mantissa, exp)) => {
+            let (sign, digits) = match exp.strip_prefix('-') {
+                Some(digits) => ('-', digits),
+                None => ('+', exp),
+            };
+            format!("{mantissa}e{sign}{digits:0>2}")
+        }
+        None => text,
+    }
+}
+
+fn html(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&#34;".to_string(),
+            '\'' => "&#39;".to_string(),
+            '\0' => "\u{FFFD}".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn js(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\\' => r"\\".to_string(),
+            '\'' => r"\'".to_string(),
+            '"' => r#"\""#.to_string(),
+            '<' => r"\u003C".to_string(),
+            '>' => r"\u003E".to_string(),
+            '&' => r"\u0026".to_string(),
+            '=' => r"\u003D".to_string(),
+            c if c.is_control() => format!("\\u{:04X}", u32::from(c)),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn urlquery(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(b).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
@@ -0,0 +1,296 @@
+use super::*;
+
+/// Template split into literal text and `{{ }}` actions
+#[derive(Debug)]
+pub(super) enum Chunk {
+    Text(String),
+    Action(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Item {
+    /// `.` optionally followed by a field chain
+    Dot(Vec<String>),
+    /// Field chain directly following a `)`
+    Chain(Vec<String>),
+    Variable(String, Vec<String>),
+    Ident(String),
+    Literal(json::Value),
+    Pipe,
+    LeftParen,
+    RightParen,
+    Declare,
+    Assign,
+    Comma,
+}
+
+pub(super) fn split(template: &str) -> Result<Vec<Chunk>, GoTemplateError> {
+    let mut chunks = vec![];
+    let mut rest = template;
+    let mut trim_next = false;
+
+    while let Some(start) = rest.find("{{") {
+        let mut text = &rest[..start];
+        if trim_next {
+            text = text.trim_start();
+        }
+
+        let mut action = &rest[start + 2..];
+        if action.starts_with('-') && action[1..].starts_with(is_space) {
+            action = &action[1..];
+            text = text.trim_end();
+        }
+        if !text.is_empty() {
+            chunks.push(Chunk::Text(text.to_string()));
+        }
+
+        let end = action_end(action)?;
+        let mut content = &action[..end];
+        trim_next = content.ends_with('-') && content[..content.len() - 1].ends_with(is_space);
+        if trim_next {
+            content = &content[..content.len() - 1];
+        }
+
+        let trimmed = content.trim();
+        if !(trimmed.starts_with("/*") && trimmed.ends_with("*/")) {
+            chunks.push(Chunk::Action(content.to_string()));
+        }
+        rest = &action[end + 2..];
+    }
+
+    let text = if trim_next { rest.trim_start() } else { rest };
+    if !text.is_empty() {
+        chunks.push(Chunk::Text(text.to_string()));
+    }
+
+    Ok(chunks)
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
+// Offset of the closing `}}` skipping over quoted text and comments
+fn action_end(action: &str) -> Result<usize, GoTemplateError> {
+    let unclosed = || GoTemplateError::Parse("unclosed action".to_string());
+    if let Some(comment) = action.trim_start().strip_prefix("/*") {
+        let offset = action.len() - comment.len();
+        let close = comment
+            .find("*/")
+            .ok_or_else(|| GoTemplateError::Parse("unclosed comment".to_string()))?;
+        let after = offset + close + 2;
+        return action[after..]
+            .find("}}")
+            .map(|end| after + end)
+            .ok_or_else(unclosed);
+    }
+
+    let mut quote = None;
+    let mut escaped = false;
+    for (idx, c) in action.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some('`') if c == '`' => quote = None,
+            Some(q) if q != '`' && c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if matches!(c, '"' | '\'' | '`') => quote = Some(c),
+            None if action[idx..].starts_with("}}") => return Ok(idx),
+            None => {}
+        }
+    }
+
+    Err(unclosed())
+}
+
+/// Tokens of a single action
+pub(super) fn items(action: &str) -> Result<Vec<Item>, GoTemplateError> {
+    let chars = action.chars().collect::<Vec<_>>();
+    let mut items = vec![];
+    let mut pos = 0;
+
+    while let Some(&c) = chars.get(pos) {
+        match c {
+            c if is_space(c) => pos += 1,
+            '|' => {
+                items.push(Item::Pipe);
+                pos += 1;
+            }
+            '(' => {
+                items.push(Item::LeftParen);
+                pos += 1;
+            }
+            ')' => {
+                items.push(Item::RightParen);
+                pos += 1;
+                if chars.get(pos) == Some(&'.') {
+                    let (chain, next) = chain(&chars, pos);
+                    if !chain.is_empty() {
+                        items.push(Item::Chain(chain));
+                        pos = next;
+                    }
+                }
+            }
+            ',' => {
+                items.push(Item::Comma);
+                pos += 1;
+            }
+            ':' if chars.get(pos + 1) == Some(&'=') => {
+                items.push(Item::Declare);
+                pos += 2;
+            }
+            '=' => {
+                items.push(Item::Assign);
+                pos += 1;
+            }
+            '.' if !chars.get(pos + 1).is_some_and(char::is_ascii_digit) => {
+                let (chain, next) = chain(&chars, pos);
+                items.push(Item::Dot(chain));
+                pos = if next == pos { pos + 1 } else { next };
+            }
+            '$' => {
+                let (name, next) = ident(&chars, pos + 1);
+                let (chain, next) = chain(&chars, next);
+                items.push(Item::Variable(format!("${name}"), chain));
+                pos = next;
+            }
+            '"' | '`' | '\'' => {
+                let (literal, next) = quoted(&chars, pos)?;
+                items.push(Item::Literal(literal));
+                pos = next;
+            }
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => {
+                let (number, next) = number(&chars, pos)?;
+                items.push(Item::Literal(number));
+                pos = next;
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let (name, next) = ident(&chars, pos);
+                let item = match name.as_str() {
+                    "true" => Item::Literal(json::Value::Bool(true)),
+                    "false" => Item::Literal(json::Value::Bool(false)),
+                    "nil" => Item::Literal(json::Value::Null),
+                    _ => Item::Ident(name),
+                };
+                items.push(item);
+                pos = next;
+            }
+            other => {
+                return Err(GoTemplateError::Parse(format!(
+                    "unexpected {other:?} in command"
+                )));
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+fn ident(chars: &[char], mut pos: usize) -> (String, usize) {
+    let start = pos;
+    while chars
+        .get(pos)
+        .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+    {
+        pos += 1;
+    }
+    (chars[start..pos].iter().collect(), pos)
+}
+
+// `.a.b.c` starting at a `.`
+fn chain(chars: &[char], mut pos: usize) -> (Vec<String>, usize) {
+    let mut fields = vec![];
+    while chars.get(pos) == Some(&'.') {
+        let (name, next) = ident(chars, pos + 1);
+        if name.is_empty() {
+            break;
+        }
+        fields.push(name);
+        pos = next;
+    }
+    (fields, pos)
+}
+
+fn quoted(chars: &[char], pos: usize) -> Result<(json::Value, usize), GoTemplateError> {
+    let quote = chars[pos];
+    let mut text = String::new();
+    let mut idx = pos + 1;
+    loop {
+        let c = *chars
+            .get(idx)
+            .ok_or_else(|| GoTemplateError::Parse("unterminated quoted string".to_string()))?;
+        idx += 1;
+        match c {
+            '\\' if quote != '`' => {
+                let escaped = chars.get(idx).copied().unwrap_or_default();
+                idx += 1;
+                match escaped {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    'r' => text.push('\r'),
+                    '0' => text.push('\0'),
+                    other => text.push(other),
+                }
+            }
+            c if c == quote => break,
+            c => text.push(c),
+        }
+    }
+
+    let literal = if quote == '\'' {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => json::Value::from(u32::from(c)),
+            _ => {
+                return Err(GoTemplateError::Parse(format!(
+                    "malformed character constant: '{text}'"
+                )));
+            }
+        }
+    } else {
+        json::Value::String(text)
+    };
+
+    Ok((literal, idx))
+}
+
+fn number(chars: &[char], pos: usize) -> Result<(json::Value, usize), GoTemplateError> {
+    let mut end = pos;
+    while chars
+        .get(end)
+        .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'))
+    {
+        // Sign is only allowed at the start or after an exponent
+        if matches!(chars[end], '-' | '+') && end != pos && !matches!(chars[end - 1], 'e' | 'E') {
+            break;
+        }
+        end += 1;
+    }
+
+    let text = chars[pos..end].iter().collect::<String>();
+    let bad = || GoTemplateError::Parse(format!("bad number syntax: {text:?}"));
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text.trim_start_matches('+')),
+    };
+
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        let value = i64::from_str_radix(hex, 16).map_err(|_| bad())?;
+        json::Value::from(if negative { -value } else { value })
+    } else if let Ok(value) = text.trim_start_matches('+').parse::<i64>() {
+        json::Value::from(value)
+    } else {
+        let value = text
+            .trim_start_matches('+')
+            .parse::<f64>()
+            .map_err(|_| bad())?;
+        json::Number::from_f64(value)
+            .map(json::Value::Number)
+            .ok_or_else(bad)?
+    };
+
+    Ok((value, end))
+}
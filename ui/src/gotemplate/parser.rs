@@ -0,0 +1,188 @@
+use super::lexer::Chunk;
+use super::lexer::Item;
+use super::*;
+
+pub(super) fn parse(template: &str) -> Result<Vec<Node>, GoTemplateError> {
+    let mut parser = Parser {
+        chunks: lexer::split(template)?.into_iter(),
+    };
+    match parser.list()? {
+        (nodes, None) => Ok(nodes),
+        (_, Some(Terminator::End)) => Err(error("unexpected {{end}}")),
+        (_, Some(Terminator::Else(_))) => Err(error("unexpected {{else}}")),
+    }
+}
+
+enum Terminator {
+    End,
+    Else(Vec<Item>),
+}
+
+struct Parser {
+    chunks: std::vec::IntoIter<Chunk>,
+}
+
+impl Parser {
+    fn list(&mut self) -> Result<(Vec<Node>, Option<Terminator>), GoTemplateError> {
+        let mut nodes = vec![];
+        while let Some(chunk) = self.chunks.next() {
+            let action = match chunk {
+                Chunk::Text(text) => {
+                    nodes.push(Node::Text(text));
+                    continue;
+                }
+                Chunk::Action(action) => lexer::items(&action)?,
+            };
+
+            let keyword = match action.first() {
+                Some(Item::Ident(keyword)) => keyword.as_str(),
+                Some(_) => "",
+                None => return Err(error("missing value for command")),
+            };
+            let node = match keyword {
+                "end" if action.len() == 1 => return Ok((nodes, Some(Terminator::End))),
+                "else" => return Ok((nodes, Some(Terminator::Else(action[1..].to_vec())))),
+                "if" => self.control(Control::If, &action[1..])?,
+                "range" => self.control(Control::Range, &action[1..])?,
+                "with" => self.control(Control::With, &action[1..])?,
+                "break" if action.len() == 1 => Node::Break,
+                "continue" if action.len() == 1 => Node::Continue,
+                "define" | "template" | "block" => {
+                    return Err(error(format!("{{{{{keyword}}}}} is not supported")));
+                }
+                _ => Node::Action(pipeline(&action)?),
+            };
+            nodes.push(node);
+        }
+
+        Ok((nodes, None))
+    }
+
+    fn control(&mut self, control: Control, items: &[Item]) -> Result<Node, GoTemplateError> {
+        let keyword = format!("{control:?}").to_lowercase();
+        let pipeline = pipeline(items)?;
+        if pipeline.commands.is_empty() {
+            return Err(error(format!("missing value for {keyword}")));
+        }
+
+        let (body, terminator) = self.list()?;
+        let otherwise = match terminator {
+            Some(Terminator::End) => vec![],
+            Some(Terminator::Else(items)) if items.is_empty() => match self.list()? {
+                (otherwise, Some(Terminator::End)) => otherwise,
+                _ => return Err(error(format!("expected end; found else in {keyword}"))),
+            },
+            // `{{else if ...}}` and `{{else with ...}}` chain into a nested control
+            Some(Terminator::Else(items))
+                if control != Control::Range
+                    && items.first() == Some(&Item::Ident(keyword.clone())) =>
+            {
+                vec![self.control(control, &items[1..])?]
+            }
+            Some(Terminator::Else(_)) => return Err(error("unexpected else arguments")),
+            None => return Err(error(format!("unexpected EOF in {keyword}"))),
+        };
+
+        Ok(Node::Control(control, pipeline, body, otherwise))
+    }
+}
+
+fn pipeline(items: &[Item]) -> Result<Pipeline, GoTemplateError> {
+    let (variables, declare, items) = match items {
+        [
+            Item::Variable(key, k),
+            Item::Comma,
+            Item::Variable(value, v),
+            Item::Declare,
+            rest @ ..,
+        ] if k.is_empty() && v.is_empty() => (vec![key.clone(), value.clone()], true, rest),
+        [Item::Variable(name, chain), Item::Declare, rest @ ..] if chain.is_empty() => {
+            (vec![name.clone()], true, rest)
+        }
+        [Item::Variable(name, chain), Item::Assign, rest @ ..] if chain.is_empty() => {
+            (vec![name.clone()], false, rest)
+        }
+        _ => (vec![], true, items),
+    };
+
+    let mut commands = vec![];
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (idx, item) in items.iter().enumerate() {
+        match item {
+            Item::LeftParen => depth += 1,
+            Item::RightParen => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| error("unexpected right paren"))?;
+            }
+            Item::Pipe if depth == 0 => {
+                commands.push(command(&items[start..idx])?);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        return Err(error("unclosed left paren"));
+    }
+    if start < items.len() || !commands.is_empty() {
+        commands.push(command(&items[start..])?);
+    }
+
+    Ok(Pipeline {
+        variables,
+        declare,
+        commands,
+    })
+}
+
+fn command(items: &[Item]) -> Result<Command, GoTemplateError> {
+    let mut args = vec![];
+    let mut idx = 0;
+    while let Some(item) = items.get(idx) {
+        idx += 1;
+        let arg = match item {
+            Item::Dot(chain) if chain.is_empty() => Arg::Dot,
+            Item::Dot(chain) => Arg::Field(chain.clone()),
+            Item::Variable(name, chain) => Arg::Variable(name.clone(), chain.clone()),
+            Item::Ident(name) if funcs::exists(name) => Arg::Function(name.clone()),
+            Item::Ident(name) => return Err(error(format!("function {name:?} not defined"))),
+            Item::Literal(value) => Arg::Literal(value.clone()),
+            Item::LeftParen => {
+                let start = idx;
+                let mut depth = 1;
+                while depth > 0 {
+                    match items.get(idx) {
+                        Some(Item::LeftParen) => depth += 1,
+                        Some(Item::RightParen) => depth -= 1,
+                        Some(_) => {}
+                        None => return Err(error("unclosed left paren")),
+                    }
+                    idx += 1;
+                }
+                let inner = pipeline(&items[start..idx - 1])?;
+                let chain = match items.get(idx) {
+                    Some(Item::Chain(chain)) => {
+                        idx += 1;
+                        chain.clone()
+                    }
+                    _ => vec![],
+                };
+                Arg::Pipeline(Box::new(inner), chain)
+            }
+            other => return Err(error(format!("unexpected {other:?} in operand"))),
+        };
+        args.push(arg);
+    }
+
+    if args.is_empty() {
+        Err(error("missing value for command"))
+    } else {
+        Ok(Command { args })
+    }
+}
+
+fn error(message: impl ToString) -> GoTemplateError {
+    GoTemplateError::Parse(message.to_string())
+}
@@ -0,0 +1,201 @@
+use super::*;
+
+fn pod() -> json::Value {
+    json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "namespace": "default",
+            "labels": {"app": "web", "tier": "frontend"},
+            "annotations": null
+        },
+        "spec": {
+            "containers": [
+                {"name": "foo", "image": "nginx", "ports": [{"containerPort": 80}]},
+                {"name": "bar", "image": "envoy"}
+            ],
+            "priority": 1.5,
+            "replicas": 3
+        },
+        "status": {
+            "phase": "Running",
+            "containerStatuses": [
+                {"name": "foo", "ready": true, "state": {"running": {"startedAt": "2024-01-01T00:00:00Z"}}},
+                {"name": "bar", "ready": false, "state": {"waiting": {"reason": "CrashLoopBackOff"}}}
+            ]
+        },
+        "data": {"username": "bXktdXNlcm5hbWU=", "bad": "%%%"}
+    })
+}
+
+fn render(template: &str) -> Result<String, GoTemplateError> {
+    GoTemplate::new(template)?.render(&pod())
+}
+
+#[track_caller]
+fn check(template: &str, expected: &str) {
+    assert_eq!(render(template).unwrap(), expected, "template: {template}");
+}
+
+#[test]
+fn fields() {
+    check("{{.metadata.name}}", "foo");
+    check("{{ .kind }}/{{ .metadata.name }}", "Pod/foo");
+    check("{{.metadata.foo}}", "<no value>");
+    check("{{.metadata.foo.bar}}", "<no value>");
+    check("{{.spec.replicas}} {{.spec.priority}}", "3 1.5");
+    check("{{.metadata.labels}}", "map[app:web tier:frontend]");
+    check(
+        "{{.spec.containers}}",
+        "[map[image:nginx name:foo ports:[map[containerPort:80]]] map[image:envoy name:bar]]",
+    );
+    check("{{.metadata.annotations}}", "<nil>");
+}
+
+#[test]
+fn field_errors() {
+    assert_eq!(
+        render("{{.metadata.annotations.foo}}").unwrap_err(),
+        GoTemplateError::Exec("nil pointer evaluating interface {}.foo".to_string())
+    );
+    assert_eq!(
+        render("{{.kind.foo}}").unwrap_err(),
+        GoTemplateError::Exec("can't evaluate field foo in type string".to_string())
+    );
+}
+
+#[test]
+fn range() {
+    check("{{range .spec.containers}}{{.name}} {{end}}", "foo bar ");
+    check(
+        "{{range $i, $c := .spec.containers}}{{$i}}={{$c.image}};{{end}}",
+        "0=nginx;1=envoy;",
+    );
+    check(
+        "{{range $k, $v := .metadata.labels}}{{$k}}:{{$v}},{{end}}",
+        "app:web,tier:frontend,",
+    );
+    check("{{range .spec.missing}}x{{else}}empty{{end}}", "empty");
+    check("{{range 3}}{{.}}{{end}}", "012");
+    check(
+        "{{range .spec.containers}}{{if eq .name \"foo\"}}{{continue}}{{end}}{{.name}}{{end}}",
+        "bar",
+    );
+    check("{{range .spec.containers}}{{.name}}{{break}}{{end}}", "foo");
+    check(
+        "{{range .spec.containers}}{{$.metadata.name}}{{end}}",
+        "foofoo",
+    );
+}
+
+#[test]
+fn conditionals() {
+    check("{{if .status.phase}}yes{{end}}", "yes");
+    check("{{if .status.missing}}yes{{else}}no{{end}}", "no");
+    check(
+        "{{if eq .status.phase \"Pending\"}}P{{else if eq .status.phase \"Running\"}}R{{else}}?{{end}}",
+        "R",
+    );
+    check("{{with .metadata.labels}}{{.app}}{{end}}", "web");
+    check(
+        "{{with .metadata.missing}}x{{else with .kind}}{{.}}{{end}}",
+        "Pod",
+    );
+    check("{{if and .kind (not .missing)}}ok{{end}}", "ok");
+    check("{{or .missing \"default\"}}", "default");
+}
+
+#[test]
+fn pipelines_and_variables() {
+    check("{{.metadata.name | printf \"%s-%s\" \"x\"}}", "x-foo");
+    check("{{$name := .metadata.name}}{{$name}}", "foo");
+    check("{{$x := 1}}{{if true}}{{$x = 2}}{{end}}{{$x}}", "2");
+    check("{{(index .spec.containers 1).name}}", "bar");
+    check(
+        "{{len .spec.containers}} {{len .kind}} {{len .metadata.labels}}",
+        "2 3 2",
+    );
+}
+
+#[test]
+fn functions() {
+    check("{{index .metadata.labels \"app\"}}", "web");
+    check(
+        "{{index .spec.containers 0 \"ports\" 0 \"containerPort\"}}",
+        "80",
+    );
+    check("{{index .metadata.labels \"nope\"}}", "<no value>");
+    check("{{slice .kind 1 3}}", "od");
+    check("{{print 1 2 \"a\" 3}}", "1 2a3");
+    check("{{println .kind 1}}", "Pod 1\n");
+    check(
+        "{{printf \"%-6s|%5d|%05.2f|%q|%v|%t\" .kind 42 3.14159 \"x\" true false}}",
+        "Pod   |   42|03.14|\"x\"|true|false",
+    );
+    check("{{printf \"%d %s\" 1}}", "1 %!s(MISSING)");
+    check("{{printf \"%d\" \"a\"}}", "%!d(string=a)");
+    check(
+        "{{lt 1 2}} {{ge .spec.replicas 3}} {{gt \"b\" \"a\"}} {{ne .kind \"Pod\"}}",
+        "true true true false",
+    );
+    check("{{eq .kind \"Service\" \"Pod\"}}", "true");
+    check("{{html \"<a href='x'>\"}}", "&lt;a href=&#39;x&#39;&gt;");
+    check("{{urlquery \"a b&c\"}}", "a+b%26c");
+}
+
+#[test]
+fn kubectl_functions() {
+    check("{{.data.username | base64decode}}", "my-username");
+    check("{{exists . \"metadata\" \"name\"}}", "true");
+    check("{{exists . \"metadata\" \"missing\"}}", "false");
+    check("{{exists .spec.containers 5}}", "false");
+    check(
+        "{{range .status.containerStatuses}}{{if and (eq .name \"foo\") (exists . \"state\" \"running\")}}{{.state.running.startedAt}}{{end}}{{end}}",
+        "2024-01-01T00:00:00Z",
+    );
+    assert!(matches!(
+        render("{{.data.bad | base64decode}}"),
+        Err(GoTemplateError::Exec(_))
+    ));
+}
+
+#[test]
+fn whitespace_and_comments() {
+    check("a  {{- .kind -}}  b", "aPodb");
+    check("{{/* comment */}}x{{- /* trimmed */ -}} y", "xy");
+    check(
+        "{{range .spec.containers -}}\n  {{.name}}\n{{end}}",
+        "foo\nbar\n",
+    );
+    check("{{-3}}", "-3");
+    check("{{`raw\\n`}}", "raw\\n");
+}
+
+#[test]
+fn parse_errors() {
+    assert!(matches!(
+        GoTemplate::new("{{.metadata.name"),
+        Err(GoTemplateError::Parse(_))
+    ));
+    assert!(matches!(
+        GoTemplate::new("{{if .kind}}x"),
+        Err(GoTemplateError::Parse(_))
+    ));
+    assert!(matches!(
+        GoTemplate::new("{{end}}"),
+        Err(GoTemplateError::Parse(_))
+    ));
+    assert_eq!(
+        GoTemplate::new("{{foo .kind}}").unwrap_err(),
+        GoTemplateError::Parse("function \"foo\" not defined".to_string())
+    );
+    assert!(matches!(
+        render("{{index .kind 0}}"),
+        Err(GoTemplateError::Exec(_))
+    ));
+    assert!(matches!(
+        render("{{range .kind}}{{end}}"),
+        Err(GoTemplateError::Exec(_))
+    ));
+}
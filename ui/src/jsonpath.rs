@@ -0,0 +1,152 @@
+use std::str;
+
+use super::*;
+
+mod eval;
+mod parser;
+
+/// kubectl compatible JSONPath template,
+/// e.g. `{range .items[*]}{.metadata.name}{"\n"}{end}`
+#[derive(Clone, Debug)]
+pub struct JsonPath {
+    template: String,
+    nodes: Vec<Node>,
+}
+
+impl JsonPath {
+    pub fn new(template: impl ToString) -> Result<Self, JsonPathError> {
+        let template = template.to_string();
+        let nodes = parser::parse(&template)?;
+        Ok(Self { template, nodes })
+    }
+
+    /// Relaxed expression as accepted by custom columns,
+    /// `.metadata.name`, `metadata.name` and `{.metadata.name}` are all the same
+    pub fn relaxed(expression: &str) -> Result<Self, JsonPathError> {
+        let expression = expression.trim();
+        let expression = expression
+            .strip_prefix('{')
+            .and_then(|expression| expression.strip_suffix('}'))
+            .unwrap_or(expression);
+        let expression = if expression.starts_with(['.', '[', '$', '@']) {
+            expression.to_string()
+        } else {
+            format!(".{expression}")
+        };
+        Self::new(format!("{{{expression}}}"))
+    }
+
+    /// Every result of the template as separate text value
+    pub fn values(&self, value: &json::Value) -> Vec<String> {
+        eval::values(&self.nodes, value)
+    }
+
+    /// Render the template as text, the way `-o jsonpath` does
+    pub fn render(&self, value: &json::Value) -> String {
+        eval::execute(&self.nodes, value, false)
+    }
+
+    /// Render the template with every expression printed as JSON array,
+    /// the way `-o jsonpath-as-json` does
+    pub fn render_json(&self, value: &json::Value) -> String {
+        eval::execute(&self.nodes, value, true)
+    }
+}
+
+impl PartialEq for JsonPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.template == other.template
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.template.fmt(f)
+    }
+}
+
+impl str::FromStr for JsonPath {
+    type Err = JsonPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum JsonPathError {
+    #[error("unclosed action in template '{0}'")]
+    UnclosedAction(String),
+    #[error("unexpected {{end}} without matching {{range}}")]
+    UnexpectedEnd,
+    #[error("{{range}} without matching {{end}}")]
+    UnclosedRange,
+    #[error("unterminated string literal {0}")]
+    UnterminatedString(String),
+    #[error("unterminated array index in '{0}'")]
+    UnterminatedIndex(String),
+    #[error("invalid array index '{0}'")]
+    InvalidIndex(String),
+    #[error("invalid filter '{0}'")]
+    InvalidFilter(String),
+    #[error("unrecognized character '{1}' in expression '{0}'")]
+    UnexpectedCharacter(String, char),
+    #[error("empty field name in expression '{0}'")]
+    EmptyField(String),
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Text(String),
+    Path(Path),
+    Range(Path, Vec<Self>),
+}
+
+#[derive(Clone, Debug)]
+struct Path {
+    root: bool,
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug)]
+enum Segment {
+    /// `.name` or `['name']`
+    Field(String),
+    /// `['name1','name2']`
+    Fields(Vec<String>),
+    /// `.*` or `[*]`
+    Wildcard,
+    /// `..name`, `..*` or `..[0]`
+    Recursive(Box<Self>),
+    /// `[0]`, `[-1]` or `[0,2]`
+    Indices(Vec<i64>),
+    /// `[start:end:step]`
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    /// `[?(@.status.phase == 'Running')]`
+    Filter(Filter),
+}
+
+#[derive(Clone, Debug)]
+enum Filter {
+    Exists(Path),
+    Compare(Operand, Op, Operand),
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Path(Path),
+    Literal(json::Value),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[cfg(test)]
+mod tests;
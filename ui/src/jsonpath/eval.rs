@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+
+use serde::Serialize;
+
+use super::*;
+
+pub(super) fn execute(nodes: &[Node], root: &json::Value, as_json: bool) -> String {
+    let mut output = String::new();
+    walk(nodes, root, root, as_json, &mut output);
+    output
+}
+
+pub(super) fn values(nodes: &[Node], root: &json::Value) -> Vec<String> {
+    let mut values = vec![];
+    collect(nodes, root, root, &mut values);
+    values
+}
+
+fn collect(nodes: &[Node], root: &json::Value, current: &json::Value, values: &mut Vec<String>) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => values.push(text.clone()),
+            Node::Path(path) => values.extend(evaluate(path, root, current).into_iter().map(text)),
+            Node::Range(path, body) => {
+                for value in evaluate(path, root, current) {
+                    match value {
+                        json::Value::Array(items) => items
+                            .iter()
+                            .for_each(|item| collect(body, root, item, values)),
+                        item => collect(body, root, item, values),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn walk(
+    nodes: &[Node],
+    root: &json::Value,
+    current: &json::Value,
+    as_json: bool,
+    out: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Path(path) => {
+                let results = evaluate(path, root, current);
+                if as_json {
+                    out.push_str(&pretty(&results));
+                } else {
+                    let text = results.into_iter().map(text).collect::<Vec<_>>();
+                    out.push_str(&text.join(" "));
+                }
+            }
+            Node::Range(path, body) => {
+                for value in evaluate(path, root, current) {
+                    match value {
+                        json::Value::Array(items) => items
+                            .iter()
+                            .for_each(|item| walk(body, root, item, as_json, out)),
+                        item => walk(body, root, item, as_json, out),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn evaluate<'a>(
+    path: &Path,
+    root: &'a json::Value,
+    current: &'a json::Value,
+) -> Vec<&'a json::Value> {
+    let start = if path.root { root } else { current };
+    path.segments
+        .iter()
+        .fold(vec![start], |values, segment| apply(segment, values, root))
+}
+
+fn apply<'a>(
+    segment: &Segment,
+    values: Vec<&'a json::Value>,
+    root: &'a json::Value,
+) -> Vec<&'a json::Value> {
+    match segment {
+        Segment::Field(name) => values
+            .into_iter()
+            .filter_map(|value| value.get(name.as_str()))
+            .collect(),
+        Segment::Fields(names) => values
+            .into_iter()
+            .flat_map(|value| names.iter().filter_map(|name| value.get(name.as_str())))
+            .collect(),
+        Segment::Wildcard => values.into_iter().flat_map(children).collect(),
+        Segment::Recursive(segment) => {
+            let descendants = values.into_iter().flat_map(descendants).collect();
+            apply(segment, descendants, root)
+        }
+        Segment::Indices(indices) => values
+            .into_iter()
+            .filter_map(json::Value::as_array)
+            .flat_map(|items| {
+                indices
+                    .iter()
+                    .filter_map(|index| normalize(*index, items.len()))
+                    .filter_map(|index| items.get(index))
+            })
+            .collect(),
+        Segment::Slice(start, end, step) => values
+            .into_iter()
+            .filter_map(json::Value::as_array)
+            .flat_map(|items| slice(items, *start, *end, *step))
+            .collect(),
+        Segment::Filter(filter) => values
+            .into_iter()
+            .filter_map(json::Value::as_array)
+            .flatten()
+            .filter(|item| matches(filter, root, item))
+            .collect(),
+    }
+}
+
+fn children(value: &json::Value) -> Vec<&json::Value> {
+    match value {
+        json::Value::Array(items) => items.iter().collect(),
+        json::Value::Object(map) => map.values().collect(),
+        _ => vec![],
+    }
+}
+
+fn descendants(value: &json::Value) -> Vec<&json::Value> {
+    iter::once(value)
+        .chain(children(value).into_iter().flat_map(descendants))
+        .collect()
+}
+
+fn normalize(index: i64, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let index = if index < 0 { index + len } else { index };
+    (0..len).contains(&index).then_some(index as usize)
+}
+
+fn slice(
+    items: &[json::Value],
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+) -> Vec<&json::Value> {
+    let len = items.len() as i64;
+    let clamp = |index: i64| {
+        let index = if index < 0 { index + len } else { index };
+        index.clamp(0, len) as usize
+    };
+    let start = start.map_or(0, clamp);
+    let end = end.map_or(items.len(), clamp);
+    let step = step.filter(|step| *step > 0).unwrap_or(1) as usize;
+
+    items
+        .get(start..end.max(start))
+        .unwrap_or_default()
+        .iter()
+        .step_by(step)
+        .collect()
+}
+
+fn matches(filter: &Filter, root: &json::Value, item: &json::Value) -> bool {
+    match filter {
+        Filter::Exists(path) => !evaluate(path, root, item).is_empty(),
+        Filter::Compare(left, op, right) => {
+            let lefts = operand(left, root, item);
+            let rights = operand(right, root, item);
+            lefts
+                .iter()
+                .any(|left| rights.iter().any(|right| compare(left, *op, right)))
+        }
+    }
+}
+
+fn operand<'a>(
+    operand: &'a Operand,
+    root: &'a json::Value,
+    item: &'a json::Value,
+) -> Vec<&'a json::Value> {
+    match operand {
+        Operand::Path(path) => evaluate(path, root, item),
+        Operand::Literal(value) => vec![value],
+    }
+}
+
+fn compare(left: &json::Value, op: Op, right: &json::Value) -> bool {
+    let ordering = match (left, right) {
+        (json::Value::Number(left), json::Value::Number(right)) => left
+            .as_f64()
+            .zip(right.as_f64())
+            .and_then(|(left, right)| left.partial_cmp(&right)),
+        (json::Value::String(left), json::Value::String(right)) => Some(left.cmp(right)),
+        (left, right) if left == right => Some(Ordering::Equal),
+        _ => None,
+    };
+
+    match op {
+        Op::Eq => ordering == Some(Ordering::Equal),
+        Op::Ne => ordering != Some(Ordering::Equal),
+        Op::Lt => ordering == Some(Ordering::Less),
+        Op::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        Op::Gt => ordering == Some(Ordering::Greater),
+        Op::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+    }
+}
+
+fn text(value: &json::Value) -> String {
+    match value {
+        json::Value::String(text) => text.clone(),
+        json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+// kubectl indents JSONPath JSON output with four spaces
+fn pretty(value: &impl Serialize) -> String {
+    let mut buffer = Vec::new();
+    let formatter = json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = json::Serializer::with_formatter(&mut buffer, formatter);
+    value
+        .serialize(&mut serializer)
+        .ok()
+        .and_then(|_| String::from_utf8(buffer).ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,382 @@
+use super::*;
+
+pub(super) fn parse(template: &str) -> Result<Vec<Node>, JsonPathError> {
+    let mut stack = vec![(None, vec![])];
+    for token in tokenize(template)? {
+        let node = match token {
+            Token::Text(text) => Node::Text(text),
+            Token::Action(action) => {
+                let action = action.trim();
+                if action.is_empty() {
+                    continue;
+                } else if action == "end" {
+                    let (Some(path), nodes) = stack.pop().ok_or(JsonPathError::UnexpectedEnd)?
+                    else {
+                        return Err(JsonPathError::UnexpectedEnd);
+                    };
+                    Node::Range(path, nodes)
+                } else if let Some(expression) = action
+                    .strip_prefix("range")
+                    .filter(|expression| expression.starts_with(char::is_whitespace))
+                {
+                    stack.push((Some(parse_path(expression.trim())?), vec![]));
+                    continue;
+                } else if action.starts_with(['"', '\'']) {
+                    Node::Text(parse_string(action)?)
+                } else {
+                    Node::Path(parse_path(action)?)
+                }
+            }
+        };
+        stack
+            .last_mut()
+            .ok_or(JsonPathError::UnexpectedEnd)?
+            .1
+            .push(node);
+    }
+
+    match stack.pop() {
+        Some((None, nodes)) if stack.is_empty() => Ok(nodes),
+        _ => Err(JsonPathError::UnclosedRange),
+    }
+}
+
+enum Token {
+    Text(String),
+    Action(String),
+}
+
+fn tokenize(template: &str) -> Result<Vec<Token>, JsonPathError> {
+    let mut tokens = vec![];
+    let mut text = String::new();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            text.push(c);
+            continue;
+        }
+
+        if !text.is_empty() {
+            tokens.push(Token::Text(std::mem::take(&mut text)));
+        }
+
+        let mut action = String::new();
+        let mut quote = None;
+        let mut closed = false;
+        while let Some(c) = chars.next() {
+            match quote {
+                Some(q) => {
+                    action.push(c);
+                    if c == '\\' {
+                        action.extend(chars.next());
+                    } else if c == q {
+                        quote = None;
+                    }
+                }
+                None if c == '}' => {
+                    closed = true;
+                    break;
+                }
+                None => {
+                    if c == '"' || c == '\'' {
+                        quote = Some(c);
+                    }
+                    action.push(c);
+                }
+            }
+        }
+
+        if !closed {
+            return Err(JsonPathError::UnclosedAction(template.to_string()));
+        }
+        tokens.push(Token::Action(action));
+    }
+
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+
+    Ok(tokens)
+}
+
+/// Quoted string with `\n`, `\t`, `\r`, `\\` and quote escapes
+fn parse_string(text: &str) -> Result<String, JsonPathError> {
+    let unterminated = || JsonPathError::UnterminatedString(text.to_string());
+    let mut chars = text.chars();
+    let quote = chars.next().ok_or_else(unterminated)?;
+    let mut string = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next().ok_or_else(unterminated)? {
+                'n' => string.push('\n'),
+                't' => string.push('\t'),
+                'r' => string.push('\r'),
+                other => string.push(other),
+            },
+            c if c == quote => {
+                return if chars.as_str().trim().is_empty() {
+                    Ok(string)
+                } else {
+                    Err(unterminated())
+                };
+            }
+            c => string.push(c),
+        }
+    }
+    Err(unterminated())
+}
+
+fn parse_path(text: &str) -> Result<Path, JsonPathError> {
+    Cursor::new(text).path()
+}
+
+struct Cursor<'a> {
+    text: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        let chars = text.chars().collect();
+        Self {
+            text,
+            chars,
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn path(&mut self) -> Result<Path, JsonPathError> {
+        let root = match self.peek() {
+            Some('$') => {
+                self.bump();
+                true
+            }
+            Some('@') => {
+                self.bump();
+                false
+            }
+            _ => false,
+        };
+
+        let mut segments = vec![];
+        while let Some(c) = self.peek() {
+            match c {
+                '.' => {
+                    self.bump();
+                    match self.peek() {
+                        Some('.') => {
+                            self.bump();
+                            let segment = if self.peek() == Some('[') {
+                                self.bracket()?
+                            } else {
+                                self.field()?
+                            };
+                            segments.push(Segment::Recursive(Box::new(segment)));
+                        }
+                        // `.[0]` is the same as `[0]`, `.` alone is the current object
+                        Some('[') | None => {}
+                        Some(_) => segments.push(self.field()?),
+                    }
+                }
+                '[' => segments.push(self.bracket()?),
+                // Relaxed form without leading dot, e.g. `metadata.name`
+                _ if self.pos == 0 => segments.push(self.field()?),
+                other => {
+                    return Err(JsonPathError::UnexpectedCharacter(
+                        self.text.to_string(),
+                        other,
+                    ));
+                }
+            }
+        }
+
+        Ok(Path { root, segments })
+    }
+
+    // Field name up to the next unescaped `.` or `[`
+    fn field(&mut self) -> Result<Segment, JsonPathError> {
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\\' => {
+                    self.bump();
+                    name.extend(self.bump());
+                }
+                '.' | '[' => break,
+                c if c.is_whitespace() => {
+                    return Err(JsonPathError::UnexpectedCharacter(self.text.to_string(), c));
+                }
+                c => {
+                    self.bump();
+                    name.push(c);
+                }
+            }
+        }
+
+        match name.as_str() {
+            "" => Err(JsonPathError::EmptyField(self.text.to_string())),
+            "*" => Ok(Segment::Wildcard),
+            _ => Ok(Segment::Field(name)),
+        }
+    }
+
+    fn bracket(&mut self) -> Result<Segment, JsonPathError> {
+        let unterminated = || JsonPathError::UnterminatedIndex(self.text.to_string());
+        self.bump();
+        let start = self.pos;
+        let mut depth = 0_usize;
+        let mut quote = None;
+        loop {
+            let c = self.bump().ok_or_else(unterminated)?;
+            match (quote, c) {
+                (Some(_), '\\') => {
+                    self.bump();
+                }
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '[' | '(') => depth += 1,
+                (None, ')') => depth = depth.saturating_sub(1),
+                (None, ']') if depth == 0 => break,
+                (None, ']') => depth -= 1,
+                _ => {}
+            }
+        }
+
+        let content = self.chars[start..self.pos - 1].iter().collect::<String>();
+        bracket_segment(content.trim())
+    }
+}
+
+fn bracket_segment(content: &str) -> Result<Segment, JsonPathError> {
+    if content == "*" {
+        Ok(Segment::Wildcard)
+    } else if let Some(filter) = content
+        .strip_prefix('?')
+        .map(str::trim)
+        .and_then(|filter| filter.strip_prefix('('))
+        .and_then(|filter| filter.strip_suffix(')'))
+    {
+        parse_filter(filter.trim()).map(Segment::Filter)
+    } else if content.starts_with(['"', '\'']) {
+        let mut names = split_outside_quotes(content, ',')
+            .into_iter()
+            .map(|name| parse_string(name.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if names.len() == 1 {
+            Ok(Segment::Field(names.remove(0)))
+        } else {
+            Ok(Segment::Fields(names))
+        }
+    } else if content.contains(':') {
+        let invalid = || JsonPathError::InvalidIndex(content.to_string());
+        let parts = content
+            .split(':')
+            .map(str::trim)
+            .map(|part| {
+                if part.is_empty() {
+                    Ok(None)
+                } else {
+                    part.parse().map(Some).map_err(|_| invalid())
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match parts.as_slice() {
+            [start, end] => Ok(Segment::Slice(*start, *end, None)),
+            [start, end, step] => Ok(Segment::Slice(*start, *end, *step)),
+            _ => Err(invalid()),
+        }
+    } else {
+        content
+            .split(',')
+            .map(|index| index.trim().parse())
+            .collect::<Result<Vec<_>, _>>()
+            .map(Segment::Indices)
+            .map_err(|_| JsonPathError::InvalidIndex(content.to_string()))
+    }
+}
+
+fn parse_filter(text: &str) -> Result<Filter, JsonPathError> {
+    let operators = [
+        ("==", Op::Eq),
+        ("!=", Op::Ne),
+        ("<=", Op::Le),
+        (">=", Op::Ge),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+    ];
+
+    let mut quote = None;
+    for (idx, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, _) => {
+                if let Some((op, operator)) =
+                    operators.iter().find(|(op, _)| text[idx..].starts_with(op))
+                {
+                    let left = parse_operand(text[..idx].trim(), text)?;
+                    let right = parse_operand(text[idx + op.len()..].trim(), text)?;
+                    return Ok(Filter::Compare(left, *operator, right));
+                }
+            }
+        }
+    }
+
+    match parse_operand(text, text)? {
+        Operand::Path(path) => Ok(Filter::Exists(path)),
+        Operand::Literal(_) => Err(JsonPathError::InvalidFilter(text.to_string())),
+    }
+}
+
+fn parse_operand(text: &str, filter: &str) -> Result<Operand, JsonPathError> {
+    if text.starts_with(['@', '$']) {
+        parse_path(text).map(Operand::Path)
+    } else if text.starts_with(['"', '\'']) {
+        parse_string(text)
+            .map(json::Value::from)
+            .map(Operand::Literal)
+    } else if let Ok(value) = json::from_str::<json::Value>(text)
+        && !value.is_object()
+        && !value.is_array()
+    {
+        Ok(Operand::Literal(value))
+    } else {
+        Err(JsonPathError::InvalidFilter(filter.to_string()))
+    }
+}
+
+fn split_outside_quotes(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut quote = None;
+    let mut start = 0;
+    let mut escaped = false;
+    for (idx, c) in text.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c == separator => {
+                parts.push(&text[start..idx]);
+                start = idx + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
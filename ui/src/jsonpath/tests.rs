@@ -0,0 +1,141 @@
+use super::*;
+
+fn store() -> json::Value {
+    json::json!({
+        "kind": "List",
+        "items": [
+            {
+                "kind": "Pod",
+                "metadata": {
+                    "name": "alpha",
+                    "namespace": "default",
+                    "labels": {"app.kubernetes.io/name": "web"}
+                },
+                "spec": {"containers": [{"name": "nginx", "image": "nginx:1.25"}]},
+                "status": {"phase": "Running", "restarts": 0}
+            },
+            {
+                "kind": "Pod",
+                "metadata": {"name": "beta", "namespace": "kube-system"},
+                "spec": {"containers": [
+                    {"name": "app", "image": "app:v1"},
+                    {"name": "sidecar", "image": "envoy:v2"}
+                ]},
+                "status": {"phase": "Pending", "restarts": 3}
+            }
+        ]
+    })
+}
+
+fn render(template: &str) -> String {
+    JsonPath::new(template).unwrap().render(&store())
+}
+
+#[test]
+fn field_and_wildcard() {
+    assert_eq!(render("{.kind}"), "List");
+    assert_eq!(render("{.items[*].metadata.name}"), "alpha beta");
+    assert_eq!(render("{$.items[0].metadata.namespace}"), "default");
+    assert_eq!(render("{.items[-1].metadata.name}"), "beta");
+}
+
+#[test]
+fn literal_text() {
+    assert_eq!(render("kind: {.kind}{\"\\n\"}"), "kind: List\n");
+    assert_eq!(render("{'\\t'}{.kind}"), "\tList");
+}
+
+#[test]
+fn range() {
+    assert_eq!(
+        render("{range .items[*]}{.metadata.name}:{.status.phase}{\"\\n\"}{end}"),
+        "alpha:Running\nbeta:Pending\n"
+    );
+    assert_eq!(
+        render("{range .items[*]}[{range .spec.containers[*]}{.name},{end}]{end}"),
+        "[nginx,][app,sidecar,]"
+    );
+}
+
+#[test]
+fn filters() {
+    assert_eq!(
+        render("{.items[?(@.status.phase==\"Running\")].metadata.name}"),
+        "alpha"
+    );
+    assert_eq!(
+        render("{.items[?(@.status.restarts > 1)].metadata.name}"),
+        "beta"
+    );
+    assert_eq!(
+        render("{.items[?(@.metadata.labels)].metadata.name}"),
+        "alpha"
+    );
+    assert_eq!(
+        render("{.items[*].spec.containers[?(@.name!='app')].image}"),
+        "nginx:1.25 envoy:v2"
+    );
+}
+
+#[test]
+fn recursive_descent() {
+    assert_eq!(render("{..image}"), "nginx:1.25 app:v1 envoy:v2");
+}
+
+#[test]
+fn slices_and_unions() {
+    assert_eq!(render("{.items[1].spec.containers[0:1].name}"), "app");
+    assert_eq!(render("{.items[0,1].metadata.name}"), "alpha beta");
+    assert_eq!(
+        render("{.items[0].metadata['name','namespace']}"),
+        "alpha default"
+    );
+    assert_eq!(render("{.items[::2].metadata.name}"), "alpha");
+}
+
+#[test]
+fn escaped_keys() {
+    assert_eq!(
+        render("{.items[0].metadata.labels.app\\.kubernetes\\.io/name}"),
+        "web"
+    );
+    assert_eq!(
+        render("{.items[0].metadata.labels['app.kubernetes.io/name']}"),
+        "web"
+    );
+}
+
+#[test]
+fn objects_and_missing_keys() {
+    assert_eq!(
+        render("{.items[0].status}"),
+        r#"{"phase":"Running","restarts":0}"#
+    );
+    assert_eq!(render("{.missing}{.items[5].kind}"), "");
+}
+
+#[test]
+fn as_json() {
+    let jsonpath = JsonPath::new("{.items[*].metadata.name}").unwrap();
+    assert_eq!(
+        jsonpath.render_json(&store()),
+        "[\n    \"alpha\",\n    \"beta\"\n]"
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        JsonPath::new("{.kind").unwrap_err(),
+        JsonPathError::UnclosedAction("{.kind".to_string())
+    );
+    assert_eq!(
+        JsonPath::new("{range .items[*]}{.kind}").unwrap_err(),
+        JsonPathError::UnclosedRange
+    );
+    assert_eq!(
+        JsonPath::new("{end}").unwrap_err(),
+        JsonPathError::UnexpectedEnd
+    );
+    assert!(JsonPath::new("{.items[x]}").is_err());
+}
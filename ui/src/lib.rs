@@ -1,29 +1,59 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt;
+use std::fs;
+use std::iter;
+use std::str;
 
 use k8s_openapi_ext as k8s;
-use kube::Resource;
 use kube::ResourceExt;
 use kube::api;
 use serde_json as json;
 use serde_yaml as yaml;
 
 use rkubectl_ext::NamespaceGetExt2;
+use rkubectl_ext::NodeGetExt2;
 use rkubectl_ext::PodGetExt2;
+use rkubectl_ext::ServiceGetExt2;
 
 use k8s::ComponentConditionGetExt;
 use k8s::ComponentStatusGetExt;
 
+use k8s::appsv1;
 use k8s::authenticationv1;
 use k8s::authorizationv1;
+use k8s::autoscalingv2;
+use k8s::batchv1;
 use k8s::corev1;
 use k8s::metav1;
-
+use k8s::openapi::api::networking::v1 as networkingv1;
+use k8s::policyv1;
+use k8s::rbacv1;
+use k8s::storagev1;
+
+use show::label_data;
+use show::label_header;
+
+pub use columns::CustomColumns;
+pub use columns::CustomColumnsError;
+pub use gotemplate::GoTemplate;
+pub use gotemplate::GoTemplateError;
+pub use jsonpath::JsonPath;
+pub use jsonpath::JsonPathError;
+pub use show::Serialized;
 pub use show::Show;
+pub use show::ShowError;
 pub use show::ShowParams;
+pub use watch::WatchEvent;
+pub use watch::WatchEventType;
+pub use watch::WatchPrinter;
 
+mod columns;
 mod convert;
+mod gotemplate;
+mod jsonpath;
 mod show;
+mod watch;
 
 #[derive(Debug)]
 pub struct Ui {
@@ -39,17 +69,24 @@ impl Ui {
         }
     }
 
-    pub fn show<T>(&self, item: T, params: &ShowParams)
+    pub fn show<T>(&self, item: T, params: &ShowParams) -> Result<(), ShowError>
     where
         T: Show,
     {
-        self.print(item.output(self.namespace, params, &self.output));
+        self.print(item.output(self.namespace, params, &self.output)?);
+        Ok(())
     }
 
     pub fn print(&self, text: impl fmt::Display) {
         println!("{text}");
     }
 
+    /// Messages about the output rather than the output itself.
+    /// These go to stderr to keep stdout parseable.
+    pub fn notice(&self, text: impl fmt::Display) {
+        eprintln!("{text}");
+    }
+
     pub fn output_deprecated(&self) -> &OutputFormat {
         &self.output
     }
@@ -59,84 +96,148 @@ impl Ui {
     }
 }
 
-// [(-o|--output=)json|yaml|name|go-template|go-template-file|template|templatefile|jsonpath|jsonpath-as-json|jsonpath-file|custom-columns|custom-columns-file|wide]
-#[derive(Clone, Copy, Debug, Default, PartialEq, clap::ValueEnum)]
+// [(-o|--output=)json|yaml|name|go-template|go-template-file|template|templatefile|jsonpath|jsonpath-as-json|jsonpath-file|custom-columns|custom-columns-file|wide|csv|tsv|markdown]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum OutputFormat {
     #[default]
-    #[value(skip)]
     Normal,
     Json,
     Yaml,
     Name,
-    GoTemplate,
-    GoTemplateFile,
-    Template,
-    TemplateFile,
-    JsonPath,
-    JsonPathAsJson,
-    JsonPathFile,
-    CustomColumns,
-    CustomColumnsFile,
+    GoTemplate(GoTemplate),
+    GoTemplateFile(GoTemplate),
+    Template(GoTemplate),
+    TemplateFile(GoTemplate),
+    JsonPath(JsonPath),
+    JsonPathAsJson(JsonPath),
+    JsonPathFile(JsonPath),
+    CustomColumns(CustomColumns),
+    CustomColumnsFile(CustomColumns),
     Wide,
+    Csv,
+    Tsv,
+    Markdown,
 }
 
-impl OutputFormat {
-    pub fn objects(&self, objects: &[api::DynamicObject]) {
-        objects.iter().for_each(|object| self.object(object));
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = match self {
+            Self::Normal => "",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Name => "name",
+            Self::GoTemplate(_) => "go-template",
+            Self::GoTemplateFile(_) => "go-template-file",
+            Self::Template(_) => "template",
+            Self::TemplateFile(_) => "templatefile",
+            Self::JsonPath(_) => "jsonpath",
+            Self::JsonPathAsJson(_) => "jsonpath-as-json",
+            Self::JsonPathFile(_) => "jsonpath-file",
+            Self::CustomColumns(_) => "custom-columns",
+            Self::CustomColumnsFile(_) => "custom-columns-file",
+            Self::Wide => "wide",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Markdown => "markdown",
+        };
+        format.fmt(f)
     }
+}
 
-    pub fn object(&self, object: &api::DynamicObject) {
-        let name = object.name_any();
-        match self {
-            Self::Normal => {
-                let types = object.types.as_ref();
-                println!("{types:?}/{name}");
+impl str::FromStr for OutputFormat {
+    type Err = OutputFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, argument) = match s.split_once('=') {
+            Some((format, argument)) => (format, Some(argument)),
+            None => (s, None),
+        };
+
+        match (format, argument) {
+            ("json", None) => Ok(Self::Json),
+            ("yaml", None) => Ok(Self::Yaml),
+            ("name", None) => Ok(Self::Name),
+            ("go-template", Some(template)) => Ok(Self::GoTemplate(template.parse()?)),
+            ("go-template-file", Some(path)) => Ok(Self::GoTemplateFile(read(path)?.parse()?)),
+            ("template", Some(template)) => Ok(Self::Template(template.parse()?)),
+            ("templatefile", Some(path)) => Ok(Self::TemplateFile(read(path)?.parse()?)),
+            ("jsonpath", Some(template)) => Ok(Self::JsonPath(template.parse()?)),
+            ("jsonpath-as-json", Some(template)) => Ok(Self::JsonPathAsJson(template.parse()?)),
+            ("jsonpath-file", Some(path)) => {
+                Ok(Self::JsonPathFile(read(path)?.trim_end().parse()?))
             }
-            Self::Json => todo!(),
-            Self::Yaml => todo!(),
-            Self::Name => todo!(),
-            Self::GoTemplate => todo!(),
-            Self::GoTemplateFile => todo!(),
-            Self::Template => todo!(),
-            Self::TemplateFile => todo!(),
-            Self::JsonPath => todo!(),
-            Self::JsonPathAsJson => todo!(),
-            Self::JsonPathFile => todo!(),
-            Self::CustomColumns => todo!(),
-            Self::CustomColumnsFile => todo!(),
-            Self::Wide => {
-                let kind = object
-                    .types
-                    .as_ref()
-                    .map(|types| types.kind.as_str())
-                    .unwrap_or_default();
-                let data = object
-                    .data
-                    .as_array()
-                    .map(|arr| arr.len())
-                    .unwrap_or_default();
-                let age = object
-                    .meta()
-                    .creation_timestamp
-                    .clone()
-                    .map(|time| time.0)
-                    .unwrap_or_default();
-                println!("{kind}/{name} {data} {age}");
+            (
+                "go-template" | "go-template-file" | "template" | "templatefile" | "jsonpath"
+                | "jsonpath-as-json" | "jsonpath-file",
+                None,
+            ) => Err(OutputFormatError::MissingTemplate(format.to_string())),
+            ("custom-columns", Some(spec)) => Ok(Self::CustomColumns(spec.parse()?)),
+            ("custom-columns-file", Some(path)) => Ok(Self::CustomColumnsFile(
+                CustomColumns::from_template(&read(path)?)?,
+            )),
+            ("custom-columns" | "custom-columns-file", None) => {
+                Err(CustomColumnsError::Empty.into())
             }
+            ("wide", None) => Ok(Self::Wide),
+            ("csv", None) => Ok(Self::Csv),
+            ("tsv", None) => Ok(Self::Tsv),
+            ("markdown", None) => Ok(Self::Markdown),
+            _ => Err(OutputFormatError::Unknown(s.to_string())),
         }
     }
+}
+
+fn read(path: &str) -> Result<String, OutputFormatError> {
+    fs::read_to_string(path).map_err(|err| OutputFormatError::ReadFile(path.to_string(), err))
+}
 
+#[derive(Debug, thiserror::Error)]
+pub enum OutputFormatError {
+    #[error(
+        "unable to match a printer suitable for the output format \"{0}\", allowed formats are: json, yaml, name, go-template, go-template-file, template, templatefile, jsonpath, jsonpath-as-json, jsonpath-file, custom-columns, custom-columns-file, wide, csv, tsv, markdown"
+    )]
+    Unknown(String),
+    #[error("template format specified but no template given, use -o {0}=<template>")]
+    MissingTemplate(String),
+    #[error("error reading template {0}: {1}")]
+    ReadFile(String, #[source] std::io::Error),
+    #[error(transparent)]
+    GoTemplate(#[from] GoTemplateError),
+    #[error(transparent)]
+    JsonPath(#[from] JsonPathError),
+    #[error(transparent)]
+    CustomColumns(#[from] CustomColumnsError),
+}
+
+impl OutputFormat {
     pub fn is_wide(&self) -> bool {
         matches!(self, Self::Wide)
     }
+
+    /// Formats built from the `header`/`data` rows of `Show`
+    pub fn is_table(&self) -> bool {
+        matches!(
+            self,
+            Self::Normal | Self::Wide | Self::Csv | Self::Tsv | Self::Markdown
+        )
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
+#[serde(transparent)]
 pub struct Created<K> {
     // pub resource: CreateResource,
     pub k: K,
 }
 
+#[derive(Debug, serde::Serialize)]
+#[serde(transparent)]
+pub struct Deleted<K: kube::Resource> {
+    pub k: K,
+    #[serde(skip)]
+    pub dyntype: K::DynamicType,
+}
+
 fn default<T: Default>() -> T {
     T::default()
 }
@@ -5,99 +5,176 @@ use tabled::settings::Remove;
 use tabled::settings::Settings;
 use tabled::settings::Style;
 use tabled::settings::location::ByColumnName;
+use tabled::settings::object::Rows;
 use tabled::settings::style::On;
 
 use super::*;
 
+pub use error::ShowError;
 pub use params::ShowParams;
 
+pub(crate) use labels::label_data;
+pub(crate) use labels::label_header;
+
+use sort::sort_items;
+use sort::sorted;
+
+mod error;
 mod impls;
+mod labels;
 mod params;
+mod sort;
 
 type TableSettings = Settings<Settings<Settings, Style<(), (), (), (), (), On, 0, 0>>, Padding>;
 
-pub trait Show {
+/// Object safe access to the serde representation every `Show` type has
+pub trait Serialized {
+    fn serialized(&self) -> Result<json::Value, json::Error>;
+}
+
+impl<T> Serialized for T
+where
+    T: serde::Serialize,
+{
+    fn serialized(&self) -> Result<json::Value, json::Error> {
+        json::to_value(self)
+    }
+}
+
+/// Every output format is built on the serde representation,
+/// types only need to provide their table columns
+pub trait Show: Serialized {
     fn header(&self, output: &OutputFormat) -> Vec<String>;
     fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String>;
-    fn json(&self, params: &ShowParams) -> String;
-    fn yaml(&self, params: &ShowParams) -> String;
-    fn name(&self) -> String;
 
     fn normal(&self, params: &ShowParams, output: &OutputFormat) -> Table {
-        tabled::builder::Builder::from_iter([self.header(output), self.data(params, output)])
-            .build()
+        let header = label_header(self.header(output), params);
+        let data = label_data(self.data(params, output), self, params);
+        tabled::builder::Builder::from_iter([header, data]).build()
     }
 
     fn wide(&self, params: &ShowParams, output: &OutputFormat) -> Table {
-        tabled::builder::Builder::from_iter([self.header(output), self.data(params, output)])
-            .build()
+        let header = label_header(self.header(output), params);
+        let data = label_data(self.data(params, output), self, params);
+        tabled::builder::Builder::from_iter([header, data]).build()
     }
 
-    fn go_template(&self) -> String {
-        todo!("This method is not supported yet")
+    /// Serde representation used by every non-table output format
+    fn value(&self, params: &ShowParams) -> Result<json::Value, ShowError> {
+        let mut value = self.serialized()?;
+        if !params.show_managed_fields {
+            strip_managed_fields(&mut value);
+        }
+        sort_items(&mut value, params);
+        Ok(value)
     }
 
-    fn go_template_file(&self) -> String {
-        todo!("This method is not supported yet")
+    fn json(&self, params: &ShowParams) -> Result<String, ShowError> {
+        let value = self.value(params)?;
+        Ok(json::to_string_pretty(&value)?)
     }
 
-    fn template(&self) -> String {
-        todo!("This method is not supported yet")
+    fn yaml(&self, params: &ShowParams) -> Result<String, ShowError> {
+        let value = self.value(params)?;
+        Ok(yaml::to_string(&value)?)
     }
 
-    fn template_file(&self) -> String {
-        todo!("This method is not supported yet")
+    fn name(&self) -> Result<String, ShowError> {
+        let value = self.value(&default())?;
+        object_name(&value).ok_or_else(|| ShowError::unsupported::<Self>(&OutputFormat::Name))
     }
 
-    fn json_path(&self) -> String {
-        todo!("This method is not supported yet")
+    fn go_template(&self, params: &ShowParams, template: &GoTemplate) -> Result<String, ShowError> {
+        let value = self.value(params)?;
+        Ok(template.render(&value)?)
     }
 
-    fn json_path_as_json(&self) -> String {
-        todo!("This method is not supported yet")
+    fn json_path(&self, params: &ShowParams, jsonpath: &JsonPath) -> Result<String, ShowError> {
+        let value = self.value(params)?;
+        Ok(jsonpath.render(&value))
     }
 
-    fn json_path_file(&self) -> String {
-        todo!("This method is not supported yet")
+    fn json_path_as_json(
+        &self,
+        params: &ShowParams,
+        jsonpath: &JsonPath,
+    ) -> Result<String, ShowError> {
+        let value = self.value(params)?;
+        Ok(jsonpath.render_json(&value))
     }
 
-    fn custom_columns(&self) -> String {
-        todo!("This method is not supported yet")
+    fn custom_columns(
+        &self,
+        params: &ShowParams,
+        columns: &CustomColumns,
+    ) -> Result<String, ShowError> {
+        let rows = columns.rows(&self.value(params)?);
+        let header = Some(columns.header()).filter(|_| !params.no_headers);
+        let mut table = header
+            .into_iter()
+            .chain(rows)
+            .collect::<tabled::builder::Builder>()
+            .build();
+        table.with(self.table_settings());
+        Ok(table.to_string())
     }
 
-    fn custom_columns_file(&self) -> String {
-        todo!("This method is not supported yet")
+    /// Text for the table formats, `normal` and `wide`
+    fn table(
+        &self,
+        namespace: bool,
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> Result<String, ShowError> {
+        let mut table = if output.is_wide() {
+            self.wide(params, output)
+        } else {
+            self.normal(params, output)
+        };
+        if !namespace {
+            table.with(Remove::column(ByColumnName::new("NAMESPACE")));
+        }
+        if params.no_headers {
+            table.with(Remove::row(Rows::first()));
+        }
+        let text = match output {
+            OutputFormat::Csv => delimited(&table, ','),
+            OutputFormat::Tsv => delimited(&table, '\t'),
+            // Without the header there is no row for the separator to go under
+            OutputFormat::Markdown if params.no_headers => table
+                .with(Style::markdown().remove_horizontals())
+                .to_string(),
+            OutputFormat::Markdown => table.with(Style::markdown()).to_string(),
+            _ => table.with(self.table_settings()).to_string(),
+        };
+        Ok(text)
     }
 
-    fn output(&self, namespace: bool, params: &ShowParams, output: &OutputFormat) -> String {
+    fn output(
+        &self,
+        namespace: bool,
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> Result<String, ShowError> {
         match output {
-            OutputFormat::Normal => {
-                let mut table = self.normal(params, output);
-                table.with(self.table_settings());
-                if !namespace {
-                    table.with(Remove::column(ByColumnName::new("NAMESPACE")));
-                }
-                table.to_string()
-            }
+            OutputFormat::Normal
+            | OutputFormat::Wide
+            | OutputFormat::Csv
+            | OutputFormat::Tsv
+            | OutputFormat::Markdown => self.table(namespace, params, output),
             OutputFormat::Json => self.json(params),
             OutputFormat::Yaml => self.yaml(params),
-            OutputFormat::Name => self.name().to_string(),
-            OutputFormat::GoTemplate => self.go_template(),
-            OutputFormat::GoTemplateFile => self.go_template_file(),
-            OutputFormat::Template => self.template(),
-            OutputFormat::TemplateFile => self.template_file(),
-            OutputFormat::JsonPath => self.json_path(),
-            OutputFormat::JsonPathAsJson => self.json_path_as_json(),
-            OutputFormat::JsonPathFile => self.json_path_file(),
-            OutputFormat::CustomColumns => self.custom_columns(),
-            OutputFormat::CustomColumnsFile => self.custom_columns_file(),
-            OutputFormat::Wide => {
-                let mut table = self.wide(params, output);
-                table.with(self.table_settings());
-                if !namespace {
-                    table.with(Remove::column(ByColumnName::new("NAMESPACE")));
-                }
-                table.to_string()
+            OutputFormat::Name => self.name(),
+            OutputFormat::GoTemplate(template)
+            | OutputFormat::GoTemplateFile(template)
+            | OutputFormat::Template(template)
+            | OutputFormat::TemplateFile(template) => self.go_template(params, template),
+            OutputFormat::JsonPath(jsonpath) | OutputFormat::JsonPathFile(jsonpath) => {
+                self.json_path(params, jsonpath)
+            }
+            OutputFormat::JsonPathAsJson(jsonpath) => self.json_path_as_json(params, jsonpath),
+            OutputFormat::CustomColumns(columns) | OutputFormat::CustomColumnsFile(columns) => {
+                self.custom_columns(params, columns)
             }
         }
     }
@@ -112,19 +189,26 @@ pub trait Show {
 
 impl<T> Show for Vec<T>
 where
-    T: Show,
+    T: Show + serde::Serialize,
 {
     fn header(&self, output: &OutputFormat) -> Vec<String> {
-        todo!("Not supported on Vec<T> for {output:?}")
+        self.first()
+            .map(|item| item.header(output))
+            .unwrap_or_default()
     }
 
-    fn data(&self, _params: &ShowParams, output: &OutputFormat) -> Vec<String> {
-        todo!("Not supported on Vec<T> for {output:?}")
+    /// Single row of a list is the one of its first item, `normal` and `wide` show them all
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        self.first()
+            .map(|item| item.data(params, output))
+            .unwrap_or_default()
     }
 
     fn normal(&self, params: &ShowParams, output: &OutputFormat) -> tabled::Table {
-        let header = self.first().map(|t| t.header(output));
-        let data = self.iter().map(|t| t.data(params, output));
+        let header = self.first().map(|t| label_header(t.header(output), params));
+        let data = sorted(self, params, |t| t.serialized().ok().map(Cow::Owned))
+            .into_iter()
+            .map(|t| label_data(t.data(params, output), t, params));
         let builder = header
             .into_iter()
             .chain(data)
@@ -133,8 +217,10 @@ where
     }
 
     fn wide(&self, params: &ShowParams, output: &OutputFormat) -> Table {
-        let header = self.first().map(|t| t.header(output));
-        let data = self.iter().map(|t| t.data(params, output));
+        let header = self.first().map(|t| label_header(t.header(output), params));
+        let data = sorted(self, params, |t| t.serialized().ok().map(Cow::Owned))
+            .into_iter()
+            .map(|t| label_data(t.data(params, output), t, params));
         let builder = header
             .into_iter()
             .chain(data)
@@ -142,44 +228,205 @@ where
         builder.build()
     }
 
-    fn yaml(&self, params: &ShowParams) -> String {
-        self.iter()
-            .map(|item| item.yaml(params))
-            .collect::<Vec<_>>()
-            .join("")
+    fn value(&self, params: &ShowParams) -> Result<json::Value, ShowError> {
+        let items = self
+            .iter()
+            .map(|item| item.value(params))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut value = json::json!({
+            "apiVersion": "v1",
+            "kind": "List",
+            "items": items,
+            "metadata": {"resourceVersion": ""},
+        });
+        sort_items(&mut value, params);
+        Ok(value)
     }
 
-    fn json(&self, params: &ShowParams) -> String {
+    fn name(&self) -> Result<String, ShowError> {
         self.iter()
-            .map(|item| item.json(params))
-            .collect::<Vec<_>>()
-            .join("\n")
+            .map(Show::name)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|names| names.join("\n"))
     }
+}
 
-    fn name(&self) -> String {
-        todo!()
+/// Table rows as delimiter separated values, quoting cells the way CSV does
+fn delimited(table: &Table, delimiter: char) -> String {
+    let escape = |cell: &str| {
+        if cell.contains([delimiter, '"', '\n']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    };
+    table
+        .get_records()
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| escape(cell.as_ref()))
+                .collect::<Vec<_>>()
+                .join(&delimiter.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn strip_managed_fields(value: &mut json::Value) {
+    if let Some(metadata) = value
+        .get_mut("metadata")
+        .and_then(json::Value::as_object_mut)
+    {
+        metadata.remove("managedFields");
+    }
+    if let Some(items) = value.get_mut("items").and_then(json::Value::as_array_mut) {
+        items.iter_mut().for_each(strip_managed_fields);
     }
 }
 
+/// `kind.group/name` out of the serde representation of an object
+fn object_name(value: &json::Value) -> Option<String> {
+    let name = value.get("metadata")?.get("name")?.as_str()?;
+    let Some(kind) = value.get("kind").and_then(json::Value::as_str) else {
+        return Some(name.to_string());
+    };
+    let api_version = value
+        .get("apiVersion")
+        .and_then(json::Value::as_str)
+        .unwrap_or_default();
+    let (group, _version) = api_version.rsplit_once('/').unwrap_or_default();
+    Some(qualified_name(kind, group, name))
+}
+
 fn name<K>(object: &K, params: &ShowParams) -> String
 where
     K: kube::Resource,
     K::DynamicType: Default,
 {
-    let name = object.name_any();
     if params.show_kind {
-        let kind = K::kind(&default()).to_lowercase();
-        format!("{kind}/{name}")
+        kind_name(object)
+    } else {
+        object.name_any()
+    }
+}
+
+/// `kind.group/name` the way `-o name` prints objects
+fn kind_name<K>(object: &K) -> String
+where
+    K: kube::Resource,
+    K::DynamicType: Default,
+{
+    let dt = default();
+    qualified_name(&K::kind(&dt), &K::group(&dt), &object.name_any())
+}
+
+fn qualified_name(kind: &str, group: &str, name: &str) -> String {
+    let kind = qualified_kind(kind, group);
+    format!("{kind}/{name}")
+}
+
+/// `kind.group` the way kubectl refers to resources in messages
+fn qualified_kind(kind: &str, group: &str) -> String {
+    let kind = kind.to_lowercase();
+    if group.is_empty() {
+        kind
     } else {
-        name
+        format!("{kind}.{group}")
     }
 }
 
-fn age(time: metav1::Time) -> String {
-    let delta = k8s::openapi::chrono::Utc::now().signed_duration_since(time.0);
+fn age(time: metav1::Time, params: &ShowParams) -> String {
+    let delta = params.now().signed_duration_since(time.0);
     human_time(delta)
 }
 
+/// `AGE` column, `<unknown>` when the object has no creation timestamp
+fn object_age<K: ResourceExt>(object: &K, params: &ShowParams) -> String {
+    object
+        .creation_timestamp()
+        .map_or_else(|| "<unknown>".to_string(), |time| age(time, params))
+}
+
+/// `CREATED AT` column
+fn created_at<K: ResourceExt>(object: &K) -> String {
+    object
+        .creation_timestamp()
+        .map(|time| {
+            time.0
+                .to_rfc3339_opts(k8s::openapi::chrono::SecondsFormat::Secs, true)
+        })
+        .unwrap_or_default()
+}
+
+fn or_none(text: Option<impl ToString>) -> String {
+    text.map_or_else(|| "<none>".to_string(), |text| text.to_string())
+}
+
+/// `key=value` pairs joined with commas, `<none>` when empty
+fn key_values(map: Option<&BTreeMap<String, String>>) -> String {
+    let pairs = map
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>();
+    if pairs.is_empty() {
+        "<none>".to_string()
+    } else {
+        pairs.join(",")
+    }
+}
+
+/// Label selector the way kubectl prints it in the `SELECTOR` column
+fn label_selector(selector: Option<&metav1::LabelSelector>) -> String {
+    let Some(selector) = selector else {
+        return "<none>".to_string();
+    };
+    let labels = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(key, value)| format!("{key}={value}"));
+    let expressions = selector
+        .match_expressions
+        .iter()
+        .flatten()
+        .map(|expression| {
+            let key = &expression.key;
+            let values = expression.values.as_deref().unwrap_or_default().join(",");
+            match expression.operator.as_str() {
+                "In" => format!("{key} in ({values})"),
+                "NotIn" => format!("{key} notin ({values})"),
+                "Exists" => key.clone(),
+                "DoesNotExist" => format!("!{key}"),
+                operator => format!("{key} {operator} ({values})"),
+            }
+        });
+    let selector = labels.chain(expressions).collect::<Vec<_>>();
+    if selector.is_empty() {
+        "<none>".to_string()
+    } else {
+        selector.join(",")
+    }
+}
+
+/// `CONTAINERS` and `IMAGES` wide columns of the workload controllers
+fn containers_and_images(template: Option<&corev1::PodTemplateSpec>) -> [String; 2] {
+    let containers = template
+        .and_then(|template| template.spec.as_ref())
+        .map(|spec| spec.containers.as_slice())
+        .unwrap_or_default();
+    let names = containers
+        .iter()
+        .map(|container| container.name.as_str())
+        .collect::<Vec<_>>();
+    let images = containers
+        .iter()
+        .filter_map(|container| container.image.as_deref())
+        .collect::<Vec<_>>();
+    [names.join(","), images.join(",")]
+}
+
 /// Mimics k8s humantime printer
 fn human_time(delta: k8s::openapi::chrono::TimeDelta) -> String {
     let days = delta.num_days();
@@ -189,7 +436,7 @@ fn human_time(delta: k8s::openapi::chrono::TimeDelta) -> String {
 
     if seconds < -1 {
         "<invalid>".to_string()
-    } else if seconds == 0 {
+    } else if seconds <= 0 {
         "0s".to_string()
     } else if seconds < 60 * 2 {
         // 2 minutes
@@ -201,6 +448,8 @@ fn human_time(delta: k8s::openapi::chrono::TimeDelta) -> String {
         } else {
             format!("{minutes}m{seconds}s")
         }
+    } else if minutes < 60 * 3 {
+        format!("{minutes}m")
     } else if hours < 8 {
         let minutes = minutes % 60; // minutes in last hour
         if minutes == 0 {
@@ -232,3 +481,25 @@ fn human_time(delta: k8s::openapi::chrono::TimeDelta) -> String {
         format!("{years}y")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        let ar = api::ApiResource::from_gvk(&kube::core::GroupVersionKind::gvk(
+            "apps",
+            "v1",
+            "Deployment",
+        ));
+        let objects = vec![
+            api::DynamicObject::new("web", &ar),
+            api::DynamicObject::new("api", &ar),
+        ];
+        assert_eq!(
+            objects.name().unwrap(),
+            "deployment.apps/web\ndeployment.apps/api"
+        );
+    }
+}
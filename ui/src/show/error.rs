@@ -0,0 +1,30 @@
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+pub enum ShowError {
+    #[error(transparent)]
+    Json(#[from] json::Error),
+
+    #[error(transparent)]
+    Yaml(#[from] yaml::Error),
+
+    #[error(transparent)]
+    GoTemplate(#[from] GoTemplateError),
+
+    #[error("{kind} cannot be printed with -o {format}")]
+    #[diagnostic(help("use -o json or -o yaml to print the whole object"))]
+    Unsupported { kind: String, format: String },
+}
+
+impl ShowError {
+    pub fn unsupported<T: ?Sized>(output: &OutputFormat) -> Self {
+        let kind = std::any::type_name::<T>()
+            .split('<')
+            .next()
+            .and_then(|path| path.rsplit("::").next())
+            .unwrap_or_default()
+            .to_string();
+        let format = output.to_string();
+        Self::Unsupported { kind, format }
+    }
+}
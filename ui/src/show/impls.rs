@@ -1,34 +1,31 @@
-use std::borrow::Cow;
-
 use super::*;
 
 mod accessreview;
+mod apps;
+mod autoscaling;
+mod batch;
 mod componentstatus;
 mod configmap;
 mod created;
+mod deleted;
+mod dynamic;
+mod endpoints;
+mod event;
 mod feature;
+#[cfg(test)]
+mod golden;
 mod namespace;
+mod networking;
 mod node;
 mod objectlist;
 mod pod;
+mod policy;
+mod quota;
+mod rbac;
+mod replicationcontroller;
+mod secret;
 mod service;
+mod storage;
 mod subjectreview;
-
-trait StripManagedFields<'a>: Clone + 'a {
-    fn maybe_strip_managed_fields(&'a self, params: &ShowParams) -> Cow<'a, Self>;
-}
-
-impl<'a, K> StripManagedFields<'a> for K
-where
-    K: Clone + kube::ResourceExt + 'a,
-{
-    fn maybe_strip_managed_fields(&'a self, params: &ShowParams) -> Cow<'a, K> {
-        if params.show_managed_fields {
-            Cow::Borrowed(self)
-        } else {
-            let mut object = self.clone();
-            object.meta_mut().managed_fields = None;
-            Cow::Owned(object)
-        }
-    }
-}
+mod table;
+mod volume;
@@ -0,0 +1,423 @@
+use super::*;
+
+impl Show for appsv1::Deployment {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "READY",
+                "UP-TO-DATE",
+                "AVAILABLE",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => [
+                "NAMESPACE",
+                "NAME",
+                "READY",
+                "UP-TO-DATE",
+                "AVAILABLE",
+                "AGE",
+            ]
+            .as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let ready = format!(
+            "{}/{}",
+            status
+                .and_then(|status| status.ready_replicas)
+                .unwrap_or_default(),
+            spec.and_then(|spec| spec.replicas).unwrap_or(1)
+        );
+        let updated = status
+            .and_then(|status| status.updated_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let available = status
+            .and_then(|status| status.available_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        let row = vec![namespace, name, ready, updated, available, age];
+        match output {
+            OutputFormat::Wide => {
+                let [containers, images] = containers_and_images(spec.map(|spec| &spec.template));
+                let selector = label_selector(spec.map(|spec| &spec.selector));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+impl Show for appsv1::ReplicaSet {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "DESIRED",
+                "CURRENT",
+                "READY",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => ["NAMESPACE", "NAME", "DESIRED", "CURRENT", "READY", "AGE"].as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let desired = spec.and_then(|spec| spec.replicas).unwrap_or(1).to_string();
+        let current = status
+            .map(|status| status.replicas)
+            .unwrap_or_default()
+            .to_string();
+        let ready = status
+            .and_then(|status| status.ready_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        let row = vec![namespace, name, desired, current, ready, age];
+        match output {
+            OutputFormat::Wide => {
+                let template = spec.and_then(|spec| spec.template.as_ref());
+                let [containers, images] = containers_and_images(template);
+                let selector = label_selector(spec.map(|spec| &spec.selector));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+impl Show for appsv1::StatefulSet {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => {
+                ["NAMESPACE", "NAME", "READY", "AGE", "CONTAINERS", "IMAGES"].as_slice()
+            }
+            _ => ["NAMESPACE", "NAME", "READY", "AGE"].as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let ready = format!(
+            "{}/{}",
+            status
+                .and_then(|status| status.ready_replicas)
+                .unwrap_or_default(),
+            spec.and_then(|spec| spec.replicas).unwrap_or(1)
+        );
+        let age = object_age(self, params);
+        let row = vec![namespace, name, ready, age];
+        match output {
+            OutputFormat::Wide => {
+                let [containers, images] = containers_and_images(spec.map(|spec| &spec.template));
+                row.into_iter().chain([containers, images]).collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+impl Show for appsv1::DaemonSet {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "DESIRED",
+                "CURRENT",
+                "READY",
+                "UP-TO-DATE",
+                "AVAILABLE",
+                "NODE SELECTOR",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => [
+                "NAMESPACE",
+                "NAME",
+                "DESIRED",
+                "CURRENT",
+                "READY",
+                "UP-TO-DATE",
+                "AVAILABLE",
+                "NODE SELECTOR",
+                "AGE",
+            ]
+            .as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let count = |count: fn(&appsv1::DaemonSetStatus) -> Option<i32>| {
+            status.and_then(count).unwrap_or_default().to_string()
+        };
+        let desired = count(|status| Some(status.desired_number_scheduled));
+        let current = count(|status| Some(status.current_number_scheduled));
+        let ready = count(|status| Some(status.number_ready));
+        let updated = count(|status| status.updated_number_scheduled);
+        let available = count(|status| status.number_available);
+        let node_selector = key_values(
+            spec.and_then(|spec| spec.template.spec.as_ref())
+                .and_then(|spec| spec.node_selector.as_ref()),
+        );
+        let age = object_age(self, params);
+        let row = vec![
+            namespace,
+            name,
+            desired,
+            current,
+            ready,
+            updated,
+            available,
+            node_selector,
+            age,
+        ];
+        match output {
+            OutputFormat::Wide => {
+                let [containers, images] = containers_and_images(spec.map(|spec| &spec.template));
+                let selector = label_selector(spec.map(|spec| &spec.selector));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+impl Show for appsv1::ControllerRevision {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "CONTROLLER", "REVISION", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let controller = or_none(self.owner_references().iter().find_map(|owner| {
+            owner.controller?.then(|| {
+                let (group, _version) = owner.api_version.rsplit_once('/').unwrap_or_default();
+                qualified_name(&owner.kind, group, &owner.name)
+            })
+        }));
+        let revision = self.revision.to_string();
+        let age = object_age(self, params);
+        vec![namespace, name, controller, revision, age]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deployment() {
+        let deployment = json::from_value::<appsv1::Deployment>(json::json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {"name": "web", "namespace": "default"},
+            "spec": {
+                "replicas": 3,
+                "selector": {
+                    "matchLabels": {"app": "web"},
+                    "matchExpressions": [{"key": "tier", "operator": "In", "values": ["a", "b"]}],
+                },
+                "template": {
+                    "spec": {"containers": [{"name": "nginx", "image": "nginx:1.27"}]},
+                },
+            },
+            "status": {"readyReplicas": 2, "updatedReplicas": 3, "availableReplicas": 2},
+        }))
+        .unwrap();
+        assert_eq!(
+            deployment.data(&default(), &OutputFormat::Wide),
+            [
+                "default",
+                "web",
+                "2/3",
+                "3",
+                "2",
+                "<unknown>",
+                "nginx",
+                "nginx:1.27",
+                "app=web,tier in (a,b)"
+            ]
+        );
+    }
+
+    #[test]
+    fn replica_set() {
+        let replica_set = json::from_value::<appsv1::ReplicaSet>(json::json!({
+            "metadata": {"name": "web-5d4f8b", "namespace": "default"},
+            "spec": {
+                "replicas": 3,
+                "selector": {"matchLabels": {"app": "web"}},
+                "template": {
+                    "spec": {"containers": [{"name": "nginx", "image": "nginx:1.27"}]},
+                },
+            },
+            "status": {"replicas": 3, "readyReplicas": 2},
+        }))
+        .unwrap();
+        assert_eq!(
+            replica_set.data(&default(), &OutputFormat::Wide),
+            [
+                "default",
+                "web-5d4f8b",
+                "3",
+                "3",
+                "2",
+                "<unknown>",
+                "nginx",
+                "nginx:1.27",
+                "app=web"
+            ]
+        );
+    }
+
+    #[test]
+    fn stateful_set() {
+        let stateful_set = json::from_value::<appsv1::StatefulSet>(json::json!({
+            "metadata": {"name": "db", "namespace": "default"},
+            "spec": {
+                "replicas": 2,
+                "selector": {"matchLabels": {"app": "db"}},
+                "template": {},
+            },
+            "status": {"replicas": 2, "readyReplicas": 1},
+        }))
+        .unwrap();
+        assert_eq!(
+            stateful_set.data(&default(), &OutputFormat::Normal),
+            ["default", "db", "1/2", "<unknown>"]
+        );
+    }
+
+    #[test]
+    fn daemon_set() {
+        let daemon_set = json::from_value::<appsv1::DaemonSet>(json::json!({
+            "metadata": {"name": "kube-proxy", "namespace": "kube-system"},
+            "spec": {
+                "selector": {"matchLabels": {"k8s-app": "kube-proxy"}},
+                "template": {
+                    "spec": {
+                        "containers": [],
+                        "nodeSelector": {"kubernetes.io/os": "linux"},
+                    },
+                },
+            },
+            "status": {
+                "desiredNumberScheduled": 3,
+                "currentNumberScheduled": 3,
+                "numberReady": 2,
+                "numberMisscheduled": 0,
+                "updatedNumberScheduled": 3,
+                "numberAvailable": 2,
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            daemon_set.data(&default(), &OutputFormat::Normal),
+            [
+                "kube-system",
+                "kube-proxy",
+                "3",
+                "3",
+                "2",
+                "3",
+                "2",
+                "kubernetes.io/os=linux",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn controller_revision() {
+        let revision = json::from_value::<appsv1::ControllerRevision>(json::json!({
+            "metadata": {
+                "name": "web-7b9c6d",
+                "namespace": "default",
+                "ownerReferences": [
+                    {"apiVersion": "v1", "kind": "Pod", "name": "web-0", "uid": "1"},
+                    {
+                        "apiVersion": "apps/v1",
+                        "kind": "StatefulSet",
+                        "name": "web",
+                        "uid": "2",
+                        "controller": true,
+                    },
+                ],
+            },
+            "revision": 2,
+        }))
+        .unwrap();
+        assert_eq!(
+            revision.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "web-7b9c6d",
+                "statefulset.apps/web",
+                "2",
+                "<unknown>"
+            ]
+        );
+
+        let revision = json::from_value::<appsv1::ControllerRevision>(json::json!({
+            "metadata": {
+                "name": "orphan",
+                "namespace": "default",
+                "ownerReferences": [
+                    {"apiVersion": "v1", "kind": "Pod", "name": "web-0", "uid": "1", "controller": true},
+                ],
+            },
+            "revision": 1,
+        }))
+        .unwrap();
+        assert_eq!(
+            revision.data(&default(), &OutputFormat::Normal)[2],
+            "pod/web-0"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use super::*;
+
+impl Show for autoscalingv2::HorizontalPodAutoscaler {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAMESPACE",
+            "NAME",
+            "REFERENCE",
+            "TARGETS",
+            "MINPODS",
+            "MAXPODS",
+            "REPLICAS",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let reference = spec
+            .map(|spec| {
+                let target = &spec.scale_target_ref;
+                format!("{}/{}", target.kind, target.name)
+            })
+            .unwrap_or_default();
+        let targets = targets(
+            spec.and_then(|spec| spec.metrics.as_deref())
+                .unwrap_or_default(),
+            status
+                .and_then(|status| status.current_metrics.as_deref())
+                .unwrap_or_default(),
+        );
+        let min_pods = spec
+            .and_then(|spec| spec.min_replicas)
+            .unwrap_or(1)
+            .to_string();
+        let max_pods = spec
+            .map(|spec| spec.max_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let replicas = status
+            .and_then(|status| status.current_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        vec![
+            namespace, name, reference, targets, min_pods, max_pods, replicas, age,
+        ]
+    }
+}
+
+/// `TARGETS` column, current and target values of the resource metrics
+fn targets(
+    metrics: &[autoscalingv2::MetricSpec],
+    current: &[autoscalingv2::MetricStatus],
+) -> String {
+    let unknown = || "<unknown>".to_string();
+    let targets = metrics
+        .iter()
+        .map(|metric| {
+            let Some(resource) = &metric.resource else {
+                return format!("<unknown>/{}", metric.type_);
+            };
+            let current = current
+                .iter()
+                .filter_map(|status| status.resource.as_ref())
+                .find(|status| status.name == resource.name)
+                .map(|status| &status.current);
+            let target = &resource.target;
+            match target.average_utilization {
+                Some(utilization) => {
+                    let current = current
+                        .and_then(|current| current.average_utilization)
+                        .map_or_else(unknown, |current| format!("{current}%"));
+                    format!("{}: {current}/{utilization}%", resource.name)
+                }
+                None => {
+                    let current = current
+                        .and_then(|current| current.average_value.as_ref())
+                        .map_or_else(unknown, |value| value.0.clone());
+                    let target = target
+                        .average_value
+                        .as_ref()
+                        .or(target.value.as_ref())
+                        .map_or_else(unknown, |value| value.0.clone());
+                    format!("{}: {current}/{target}", resource.name)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if targets.is_empty() {
+        "<none>".to_string()
+    } else {
+        targets.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn horizontal_pod_autoscaler() {
+        let hpa = json::from_value::<autoscalingv2::HorizontalPodAutoscaler>(json::json!({
+            "metadata": {"name": "web", "namespace": "default"},
+            "spec": {
+                "scaleTargetRef": {"apiVersion": "apps/v1", "kind": "Deployment", "name": "web"},
+                "minReplicas": 2,
+                "maxReplicas": 10,
+                "metrics": [
+                    {
+                        "type": "Resource",
+                        "resource": {
+                            "name": "cpu",
+                            "target": {"type": "Utilization", "averageUtilization": 80},
+                        },
+                    },
+                    {
+                        "type": "Resource",
+                        "resource": {
+                            "name": "memory",
+                            "target": {"type": "AverageValue", "averageValue": "500Mi"},
+                        },
+                    },
+                ],
+            },
+            "status": {
+                "currentReplicas": 3,
+                "desiredReplicas": 3,
+                "currentMetrics": [
+                    {
+                        "type": "Resource",
+                        "resource": {"name": "cpu", "current": {"averageUtilization": 45}},
+                    },
+                ],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            hpa.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "web",
+                "Deployment/web",
+                "cpu: 45%/80%, memory: <unknown>/500Mi",
+                "2",
+                "10",
+                "3",
+                "<unknown>"
+            ]
+        );
+    }
+}
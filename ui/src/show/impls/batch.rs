@@ -0,0 +1,310 @@
+use super::*;
+
+impl Show for batchv1::Job {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "STATUS",
+                "COMPLETIONS",
+                "DURATION",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => [
+                "NAMESPACE",
+                "NAME",
+                "STATUS",
+                "COMPLETIONS",
+                "DURATION",
+                "AGE",
+            ]
+            .as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let condition = |r#type: &str| {
+            status
+                .and_then(|status| status.conditions.as_ref())
+                .into_iter()
+                .flatten()
+                .any(|condition| condition.type_ == r#type && condition.status == "True")
+        };
+        let state = if condition("Failed") {
+            "Failed"
+        } else if condition("Complete") {
+            "Complete"
+        } else if condition("Suspended") {
+            "Suspended"
+        } else {
+            "Running"
+        };
+        let succeeded = status
+            .and_then(|status| status.succeeded)
+            .unwrap_or_default();
+        let completions = spec.and_then(|spec| spec.completions);
+        let parallelism = spec.and_then(|spec| spec.parallelism).unwrap_or(1);
+        let completions = match (completions, parallelism) {
+            (Some(completions), _) => format!("{succeeded}/{completions}"),
+            (None, parallelism) if parallelism > 1 => format!("{succeeded}/1 of {parallelism}"),
+            (None, _) => format!("{succeeded}/1"),
+        };
+        let duration = status
+            .and_then(|status| {
+                let start = status.start_time.as_ref()?;
+                let end = status
+                    .completion_time
+                    .as_ref()
+                    .map_or_else(|| params.now(), |time| time.0);
+                Some(human_time(end.signed_duration_since(start.0)))
+            })
+            .unwrap_or_default();
+        let age = object_age(self, params);
+        let row = vec![
+            namespace,
+            name,
+            state.to_string(),
+            completions,
+            duration,
+            age,
+        ];
+        match output {
+            OutputFormat::Wide => {
+                let [containers, images] = containers_and_images(spec.map(|spec| &spec.template));
+                let selector = label_selector(spec.and_then(|spec| spec.selector.as_ref()));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+impl Show for batchv1::CronJob {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "SCHEDULE",
+                "TIMEZONE",
+                "SUSPEND",
+                "ACTIVE",
+                "LAST SCHEDULE",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => [
+                "NAMESPACE",
+                "NAME",
+                "SCHEDULE",
+                "TIMEZONE",
+                "SUSPEND",
+                "ACTIVE",
+                "LAST SCHEDULE",
+                "AGE",
+            ]
+            .as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let schedule = spec.map(|spec| spec.schedule.as_str()).unwrap_or_default();
+        let time_zone = spec.and_then(|spec| spec.time_zone.as_ref());
+        let suspend = if spec.and_then(|spec| spec.suspend).unwrap_or_default() {
+            "True"
+        } else {
+            "False"
+        };
+        let active = status
+            .and_then(|status| status.active.as_ref())
+            .map(Vec::len)
+            .unwrap_or_default()
+            .to_string();
+        let last_schedule = or_none(
+            status
+                .and_then(|status| status.last_schedule_time.clone())
+                .map(|time| age(time, params)),
+        );
+        let age = object_age(self, params);
+        let row = vec![
+            namespace,
+            name,
+            schedule.to_string(),
+            or_none(time_zone),
+            suspend.to_string(),
+            active,
+            last_schedule,
+            age,
+        ];
+        match output {
+            OutputFormat::Wide => {
+                let job = spec.and_then(|spec| spec.job_template.spec.as_ref());
+                let [containers, images] = containers_and_images(job.map(|job| &job.template));
+                let selector = label_selector(job.and_then(|job| job.selector.as_ref()));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job() {
+        let params = ShowParams::default().with_now("2025-06-10T12:00:00Z".parse().unwrap());
+        let job = json::from_value::<batchv1::Job>(json::json!({
+            "metadata": {
+                "name": "backup",
+                "namespace": "default",
+                "creationTimestamp": "2025-06-10T11:50:00Z",
+            },
+            "spec": {
+                "completions": 3,
+                "template": {
+                    "spec": {"containers": [{"name": "backup", "image": "busybox"}]},
+                },
+            },
+            "status": {
+                "succeeded": 3,
+                "startTime": "2025-06-10T11:50:00Z",
+                "completionTime": "2025-06-10T11:52:30Z",
+                "conditions": [{"type": "Complete", "status": "True"}],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            job.data(&params, &OutputFormat::Wide),
+            [
+                "default", "backup", "Complete", "3/3", "2m30s", "10m", "backup", "busybox",
+                "<none>"
+            ]
+        );
+
+        let job = json::from_value::<batchv1::Job>(json::json!({
+            "metadata": {"name": "crunch", "namespace": "default"},
+            "spec": {"parallelism": 2, "template": {}},
+            "status": {"active": 2, "startTime": "2025-06-10T11:59:00Z"},
+        }))
+        .unwrap();
+        assert_eq!(
+            job.data(&params, &OutputFormat::Normal),
+            [
+                "default",
+                "crunch",
+                "Running",
+                "0/1 of 2",
+                "60s",
+                "<unknown>"
+            ]
+        );
+
+        let job = json::from_value::<batchv1::Job>(json::json!({
+            "metadata": {"name": "broken", "namespace": "default"},
+            "spec": {"template": {}},
+            "status": {
+                "failed": 1,
+                "conditions": [
+                    {"type": "Complete", "status": "False"},
+                    {"type": "Failed", "status": "True"},
+                ],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            job.data(&params, &OutputFormat::Normal),
+            ["default", "broken", "Failed", "0/1", "", "<unknown>"]
+        );
+    }
+
+    #[test]
+    fn cronjob() {
+        let params = ShowParams::default().with_now("2025-06-10T12:00:00Z".parse().unwrap());
+        let cronjob = json::from_value::<batchv1::CronJob>(json::json!({
+            "metadata": {
+                "name": "backup",
+                "namespace": "default",
+                "creationTimestamp": "2025-06-09T12:00:00Z",
+            },
+            "spec": {
+                "schedule": "*/5 * * * *",
+                "jobTemplate": {
+                    "spec": {
+                        "template": {
+                            "spec": {"containers": [{"name": "backup", "image": "busybox"}]},
+                        },
+                    },
+                },
+            },
+            "status": {
+                "active": [{"kind": "Job", "name": "backup-29160715"}],
+                "lastScheduleTime": "2025-06-10T11:55:00Z",
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            cronjob.data(&params, &OutputFormat::Wide),
+            [
+                "default",
+                "backup",
+                "*/5 * * * *",
+                "<none>",
+                "False",
+                "1",
+                "5m",
+                "24h",
+                "backup",
+                "busybox",
+                "<none>"
+            ]
+        );
+
+        let cronjob = json::from_value::<batchv1::CronJob>(json::json!({
+            "metadata": {"name": "report", "namespace": "default"},
+            "spec": {
+                "schedule": "0 3 * * *",
+                "timeZone": "Europe/Paris",
+                "suspend": true,
+                "jobTemplate": {},
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            cronjob.data(&params, &OutputFormat::Normal),
+            [
+                "default",
+                "report",
+                "0 3 * * *",
+                "Europe/Paris",
+                "True",
+                "0",
+                "<none>",
+                "<unknown>"
+            ]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+impl<K> Show for Deleted<K>
+where
+    K: Show + kube::Resource + serde::Serialize,
+{
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        vec![]
+    }
+
+    fn data(&self, _params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let kind = qualified_kind(&K::kind(&self.dyntype), &K::group(&self.dyntype));
+        vec![format!("{kind} \"{}\" deleted", self.k.name_any())]
+    }
+
+    fn value(&self, params: &ShowParams) -> Result<json::Value, ShowError> {
+        self.k.value(params)
+    }
+
+    fn name(&self) -> Result<String, ShowError> {
+        self.k.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s::PodExt;
+
+    use super::*;
+
+    #[test]
+    fn deleted() {
+        let deleted = Deleted {
+            k: corev1::Pod::new("foo"),
+            dyntype: (),
+        };
+        assert_eq!(deleted.name().unwrap(), "pod/foo");
+        assert_eq!(
+            deleted.data(&default(), &OutputFormat::Normal),
+            ["pod \"foo\" deleted"]
+        );
+    }
+
+    #[test]
+    fn deleted_with_group() {
+        let dyntype = api::ApiResource::from_gvk(&kube::core::GroupVersionKind::gvk(
+            "apps",
+            "v1",
+            "Deployment",
+        ));
+        let deleted = Deleted {
+            k: api::DynamicObject::new("web", &dyntype),
+            dyntype,
+        };
+        assert_eq!(
+            deleted.data(&default(), &OutputFormat::Normal),
+            ["deployment.apps \"web\" deleted"]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+impl Show for api::DynamicObject {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        // Dynamic objects carry their kind in `types` rather than in the Rust type
+        let name = if params.show_kind {
+            self.name().unwrap_or_else(|_| self.name_any())
+        } else {
+            self.name_any()
+        };
+        let age = object_age(self, params);
+        vec![namespace, name, age]
+    }
+}
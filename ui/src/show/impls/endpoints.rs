@@ -0,0 +1,78 @@
+use super::*;
+
+impl Show for corev1::Endpoints {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "ENDPOINTS", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        const MAX: usize = 3;
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let endpoints = self
+            .subsets
+            .iter()
+            .flatten()
+            .flat_map(|subset| {
+                let ports = subset.ports.as_deref().unwrap_or_default();
+                subset.addresses.iter().flatten().flat_map(move |address| {
+                    let ports = ports.iter().map(|port| Some(port.port));
+                    let ports = if ports.len() == 0 {
+                        vec![None]
+                    } else {
+                        ports.collect()
+                    };
+                    ports.into_iter().map(|port| match port {
+                        Some(port) => format!("{}:{port}", address.ip),
+                        None => address.ip.clone(),
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        let endpoints = match endpoints.len() {
+            0 => "<none>".to_string(),
+            1..=MAX => endpoints.join(","),
+            more => format!("{} + {} more...", endpoints[..MAX].join(","), more - MAX),
+        };
+        let age = object_age(self, params);
+        vec![namespace, name, endpoints, age]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints() {
+        let endpoints = json::from_value::<corev1::Endpoints>(json::json!({
+            "metadata": {"name": "web", "namespace": "default"},
+            "subsets": [{
+                "addresses": [{"ip": "10.0.0.1"}, {"ip": "10.0.0.2"}],
+                "ports": [{"port": 80}, {"port": 443}],
+            }],
+        }))
+        .unwrap();
+        assert_eq!(
+            endpoints.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "web",
+                "10.0.0.1:80,10.0.0.1:443,10.0.0.2:80 + 1 more...",
+                "<unknown>"
+            ]
+        );
+
+        let endpoints = json::from_value::<corev1::Endpoints>(json::json!({
+            "metadata": {"name": "headless", "namespace": "default"},
+        }))
+        .unwrap();
+        assert_eq!(
+            endpoints.data(&default(), &OutputFormat::Normal),
+            ["default", "headless", "<none>", "<unknown>"]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+impl Show for corev1::Event {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAMESPACE",
+            "LAST SEEN",
+            "TYPE",
+            "REASON",
+            "OBJECT",
+            "MESSAGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let last_seen = self
+            .last_timestamp
+            .clone()
+            .or_else(|| self.event_time.clone().map(|time| metav1::Time(time.0)))
+            .or_else(|| self.creation_timestamp())
+            .map_or_else(|| "<unknown>".to_string(), |time| age(time, params));
+        let r#type = self.type_.clone().unwrap_or_default();
+        let reason = self.reason.clone().unwrap_or_default();
+        let object = &self.involved_object;
+        let object = format!(
+            "{}/{}",
+            object.kind.as_deref().unwrap_or_default().to_lowercase(),
+            object.name.as_deref().unwrap_or_default()
+        );
+        let message = self.message.clone().unwrap_or_default();
+        vec![namespace, last_seen, r#type, reason, object, message]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event() {
+        let params = ShowParams::default().with_now("2025-06-10T12:00:00Z".parse().unwrap());
+        let event = json::from_value::<corev1::Event>(json::json!({
+            "metadata": {"name": "web-1.17f", "namespace": "default"},
+            "lastTimestamp": "2025-06-10T11:58:00Z",
+            "type": "Warning",
+            "reason": "BackOff",
+            "involvedObject": {"kind": "Pod", "name": "web-1"},
+            "message": "Back-off restarting failed container",
+        }))
+        .unwrap();
+        assert_eq!(
+            event.data(&params, &OutputFormat::Normal),
+            [
+                "default",
+                "2m",
+                "Warning",
+                "BackOff",
+                "pod/web-1",
+                "Back-off restarting failed container"
+            ]
+        );
+
+        let event = json::from_value::<corev1::Event>(json::json!({
+            "metadata": {"name": "web-1.18a", "namespace": "default"},
+            "eventTime": "2025-06-10T11:59:30.000000Z",
+            "involvedObject": {"kind": "Pod", "name": "web-1"},
+        }))
+        .unwrap();
+        assert_eq!(event.data(&params, &OutputFormat::Normal)[1], "30s");
+    }
+}
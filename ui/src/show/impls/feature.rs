@@ -8,16 +8,33 @@ impl Show for rkubectl_features::Feature {
     fn data(&self, _params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
         self.fields().into_iter().map(Into::into).collect()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkubectl_features::Feature;
+
+    use super::*;
 
-    fn json(&self, _params: &ShowParams) -> String {
-        json::to_string_pretty(self).unwrap_or_default()
+    fn feature() -> Feature {
+        Feature {
+            name: "SidecarContainers".to_string(),
+            stage: "BETA".to_string(),
+            enabled: true,
+        }
     }
 
-    fn yaml(&self, _params: &ShowParams) -> String {
-        yaml::to_string(self).unwrap_or_default()
+    #[test]
+    fn json() {
+        let value = feature().value(&default()).unwrap();
+        assert_eq!(value["name"], "SidecarContainers");
+        assert_eq!(value["enabled"], true);
     }
 
-    fn name(&self) -> String {
-        unreachable!()
+    #[test]
+    fn name_is_unsupported() {
+        let err = feature().name().unwrap_err();
+        assert!(matches!(err, ShowError::Unsupported { .. }));
+        assert_eq!(err.to_string(), "Feature cannot be printed with -o name");
     }
 }
@@ -0,0 +1,87 @@
+//! Table output compared with what `kubectl get` prints for the same objects.
+//! The time is pinned to 2025-06-10T12:00:00Z for the ages to be checked as well.
+
+use kube::core::ObjectList;
+use serde::de::DeserializeOwned;
+
+use super::*;
+
+fn golden<K>(list: &str, namespace: bool, output: &OutputFormat, expected: &str)
+where
+    K: Clone + DeserializeOwned + Show + serde::Serialize,
+{
+    let params = ShowParams::default().with_now("2025-06-10T12:00:00Z".parse().unwrap());
+    let list = json::from_str::<ObjectList<K>>(list).unwrap();
+    let text = list.output(namespace, &params, output).unwrap();
+    // kubectl does not pad the last column
+    let text = text.lines().map(str::trim_end).collect::<Vec<_>>();
+    assert_eq!(text, expected.lines().collect::<Vec<_>>());
+}
+
+#[test]
+fn pods() {
+    let pods = include_str!("testdata/pods.json");
+    golden::<corev1::Pod>(
+        pods,
+        false,
+        &OutputFormat::Normal,
+        include_str!("testdata/pods.txt"),
+    );
+    golden::<corev1::Pod>(
+        pods,
+        false,
+        &OutputFormat::Wide,
+        include_str!("testdata/pods-wide.txt"),
+    );
+}
+
+#[test]
+fn services() {
+    let services = include_str!("testdata/services.json");
+    golden::<corev1::Service>(
+        services,
+        false,
+        &OutputFormat::Normal,
+        include_str!("testdata/services.txt"),
+    );
+    golden::<corev1::Service>(
+        services,
+        false,
+        &OutputFormat::Wide,
+        include_str!("testdata/services-wide.txt"),
+    );
+}
+
+#[test]
+fn nodes() {
+    let nodes = include_str!("testdata/nodes.json");
+    golden::<corev1::Node>(
+        nodes,
+        false,
+        &OutputFormat::Normal,
+        include_str!("testdata/nodes.txt"),
+    );
+    golden::<corev1::Node>(
+        nodes,
+        false,
+        &OutputFormat::Wide,
+        include_str!("testdata/nodes-wide.txt"),
+    );
+}
+
+#[test]
+fn namespaces() {
+    let namespaces = include_str!("testdata/namespaces.json");
+    golden::<corev1::Namespace>(
+        namespaces,
+        false,
+        &OutputFormat::Normal,
+        include_str!("testdata/namespaces.txt"),
+    );
+    golden::<corev1::Namespace>(
+        namespaces,
+        false,
+        &OutputFormat::Wide,
+        include_str!("testdata/namespaces.txt"),
+    );
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+impl Show for networkingv1::Ingress {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAMESPACE",
+            "NAME",
+            "CLASS",
+            "HOSTS",
+            "ADDRESS",
+            "PORTS",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let class = or_none(spec.and_then(|spec| spec.ingress_class_name.as_ref()));
+        let hosts = spec
+            .and_then(|spec| spec.rules.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|rule| rule.host.as_deref().unwrap_or("*"))
+            .collect::<Vec<_>>();
+        let hosts = match hosts.len() {
+            0 => "*".to_string(),
+            1..=3 => hosts.join(","),
+            more => format!("{} + {} more...", hosts[..3].join(","), more - 3),
+        };
+        let address = self
+            .status
+            .as_ref()
+            .and_then(|status| status.load_balancer.as_ref())
+            .and_then(|lb| lb.ingress.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|ingress| ingress.ip.as_deref().or(ingress.hostname.as_deref()))
+            .collect::<Vec<_>>()
+            .join(",");
+        let tls = spec.and_then(|spec| spec.tls.as_ref());
+        let ports = if tls.is_some_and(|tls| !tls.is_empty()) {
+            "80, 443"
+        } else {
+            "80"
+        };
+        let age = object_age(self, params);
+        vec![
+            namespace,
+            name,
+            class,
+            hosts,
+            address,
+            ports.to_string(),
+            age,
+        ]
+    }
+}
+
+impl Show for networkingv1::IngressClass {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAME", "CONTROLLER", "PARAMETERS", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let controller = spec
+            .and_then(|spec| spec.controller.clone())
+            .unwrap_or_default();
+        let parameters = spec.and_then(|spec| spec.parameters.as_ref());
+        let parameters = or_none(parameters.map(|parameters| {
+            let group = parameters.api_group.as_deref().unwrap_or_default();
+            qualified_name(&parameters.kind, group, &parameters.name)
+        }));
+        let age = object_age(self, params);
+        vec![name, controller, parameters, age]
+    }
+}
+
+impl Show for networkingv1::NetworkPolicy {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "POD-SELECTOR", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let pod_selector = label_selector(
+            self.spec
+                .as_ref()
+                .and_then(|spec| spec.pod_selector.as_ref()),
+        );
+        let age = object_age(self, params);
+        vec![namespace, name, pod_selector, age]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ingress() {
+        let ingress = json::from_value::<networkingv1::Ingress>(json::json!({
+            "metadata": {"name": "web", "namespace": "default"},
+            "spec": {
+                "ingressClassName": "nginx",
+                "rules": [{"host": "a.example.com"}, {"host": "b.example.com"}, {}],
+                "tls": [{"hosts": ["a.example.com"]}],
+            },
+            "status": {"loadBalancer": {"ingress": [{"ip": "10.0.0.1"}]}},
+        }))
+        .unwrap();
+        assert_eq!(
+            ingress.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "web",
+                "nginx",
+                "a.example.com,b.example.com,*",
+                "10.0.0.1",
+                "80, 443",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn ingress_class() {
+        let class = json::from_value::<networkingv1::IngressClass>(json::json!({
+            "metadata": {"name": "external-lb"},
+            "spec": {
+                "controller": "example.com/ingress-controller",
+                "parameters": {
+                    "apiGroup": "k8s.example.com",
+                    "kind": "IngressParameters",
+                    "name": "external-lb",
+                },
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            class.data(&default(), &OutputFormat::Normal),
+            [
+                "external-lb",
+                "example.com/ingress-controller",
+                "ingressparameters.k8s.example.com/external-lb",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn network_policy() {
+        let policy = json::from_value::<networkingv1::NetworkPolicy>(json::json!({
+            "metadata": {"name": "db", "namespace": "default"},
+            "spec": {"podSelector": {"matchLabels": {"role": "db"}}},
+        }))
+        .unwrap();
+        assert_eq!(
+            policy.data(&default(), &OutputFormat::Normal),
+            ["default", "db", "role=db", "<unknown>"]
+        );
+    }
+}
@@ -3,58 +3,71 @@ use super::*;
 impl Show for corev1::Node {
     fn header(&self, output: &OutputFormat) -> Vec<String> {
         let header = match output {
-            OutputFormat::Normal => ["NAMESPACE", "NAME"].as_slice(),
-            OutputFormat::Wide => ["NAMESPACE", "NAME", "AGE"].as_slice(),
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => [
+                "NAME",
+                "STATUS",
+                "ROLES",
+                "AGE",
+                "VERSION",
+                "INTERNAL-IP",
+                "EXTERNAL-IP",
+                "OS-IMAGE",
+                "KERNEL-VERSION",
+                "CONTAINER-RUNTIME",
+            ]
+            .as_slice(),
+            _ => ["NAME", "STATUS", "ROLES", "AGE", "VERSION"].as_slice(),
         };
         header.iter().map(ToString::to_string).collect()
     }
 
     fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
-        let namespace = self.namespace().unwrap_or_default();
         let name = name(self, params);
-        let age = self.creation_timestamp().map(age).unwrap_or_default();
+        let status = self.kubectl_status();
+        let roles = self.roles();
+        let roles = if roles.is_empty() {
+            "<none>".to_string()
+        } else {
+            roles.join(",")
+        };
+        let age = object_age(self, params);
+        let info = self.node_info().cloned().unwrap_or_default();
+        let row = vec![name, status, roles, age, info.kubelet_version];
         match output {
-            OutputFormat::Normal => vec![namespace, name],
-            OutputFormat::Wide => vec![namespace, name, age],
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => {
+                let address = |r#type| {
+                    self.address(r#type)
+                        .map_or_else(|| "<none>".to_string(), ToString::to_string)
+                };
+                let unknown = |text: String| {
+                    if text.is_empty() {
+                        "<unknown>".to_string()
+                    } else {
+                        text
+                    }
+                };
+                let wide = [
+                    address("InternalIP"),
+                    address("ExternalIP"),
+                    unknown(info.os_image),
+                    unknown(info.kernel_version),
+                    unknown(info.container_runtime_version),
+                ];
+                row.into_iter().chain(wide).collect()
+            }
+            _ => row,
         }
     }
-
-    fn yaml(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        yaml::to_string(&data).unwrap_or_default()
-    }
-
-    fn json(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        json::to_string_pretty(&data).unwrap_or_default()
-    }
-
-    fn name(&self) -> String {
-        todo!()
-    }
 }
 
 impl Show for corev1::NodeSystemInfo {
+    // Rows come from the serde representation, see `normal`
     fn header(&self, _output: &OutputFormat) -> Vec<String> {
-        todo!()
+        vec![]
     }
 
     fn data(&self, _params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
-        todo!()
-    }
-
-    fn json(&self, _params: &ShowParams) -> String {
-        json::to_string(self).unwrap_or_default()
-    }
-
-    fn yaml(&self, _params: &ShowParams) -> String {
-        yaml::to_string(self).unwrap_or_default()
-    }
-
-    fn name(&self) -> String {
-        todo!()
+        vec![]
     }
 
     fn normal(&self, _params: &ShowParams, _output: &OutputFormat) -> tabled::Table {
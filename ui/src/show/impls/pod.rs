@@ -3,13 +3,20 @@ use super::*;
 impl Show for corev1::Pod {
     fn header(&self, output: &OutputFormat) -> Vec<String> {
         let header = match output {
-            OutputFormat::Normal => {
-                ["NAMESPACE", "NAME", "READY", "STATUS", "RESTARTS", "AGE"].as_slice()
-            }
-            OutputFormat::Wide => {
-                ["NAMESPACE", "NAME", "READY", "STATUS", "RESTARTS", "AGE"].as_slice()
-            }
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "READY",
+                "STATUS",
+                "RESTARTS",
+                "AGE",
+                "IP",
+                "NODE",
+                "NOMINATED NODE",
+                "READINESS GATES",
+            ]
+            .as_slice(),
+            _ => ["NAMESPACE", "NAME", "READY", "STATUS", "RESTARTS", "AGE"].as_slice(),
         };
         header.iter().map(ToString::to_string).collect()
     }
@@ -21,26 +28,46 @@ impl Show for corev1::Pod {
         let ready = self.ready_containers();
         let ready = format!("{ready}/{total}");
         let status = self.kubectl_status();
-        let restarts = self.restarts().to_string();
-        let age = self.creation_timestamp().map(age).unwrap_or_default();
+        let restarts = match (self.restarts(), self.last_restart()) {
+            (0, _) | (_, None) => self.restarts().to_string(),
+            (restarts, Some(time)) => format!("{restarts} ({} ago)", age(time.clone(), params)),
+        };
+        let age = object_age(self, params);
         match output {
-            OutputFormat::Normal => vec![namespace, name, ready, status, restarts, age],
-            OutputFormat::Wide => vec![namespace, name, ready, status, restarts, age],
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => {
+                let none = || "<none>".to_string();
+                let ip = self.ip().map_or_else(none, ToString::to_string);
+                let node = self.node().map_or_else(none, ToString::to_string);
+                let nominated = self.nominated_node().map_or_else(none, ToString::to_string);
+                let gates = self.readiness_gates_kubectl_status().unwrap_or_else(none);
+                vec![
+                    namespace, name, ready, status, restarts, age, ip, node, nominated, gates,
+                ]
+            }
+            _ => vec![namespace, name, ready, status, restarts, age],
         }
     }
+}
 
-    fn yaml(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        yaml::to_string(&data).unwrap_or_default()
-    }
+#[cfg(test)]
+mod tests {
+    use k8s::PodExt;
 
-    fn json(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        json::to_string_pretty(&data).unwrap_or_default()
-    }
+    use super::*;
 
-    fn name(&self) -> String {
-        todo!()
+    #[test]
+    fn managed_fields() {
+        let mut pod = corev1::Pod::new("foo");
+        pod.metadata.managed_fields = Some(vec![default()]);
+
+        let value = pod.value(&default()).unwrap();
+        assert!(value["metadata"].get("managedFields").is_none());
+
+        let params = ShowParams {
+            show_managed_fields: true,
+            ..default()
+        };
+        let value = pod.value(&params).unwrap();
+        assert!(value["metadata"].get("managedFields").is_some());
     }
 }
@@ -0,0 +1,69 @@
+use super::*;
+
+impl Show for policyv1::PodDisruptionBudget {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAMESPACE",
+            "NAME",
+            "MIN AVAILABLE",
+            "MAX UNAVAILABLE",
+            "ALLOWED DISRUPTIONS",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let int_or_string = |value: Option<&k8s::intstr::IntOrString>| match value {
+            Some(k8s::intstr::IntOrString::Int(value)) => value.to_string(),
+            Some(k8s::intstr::IntOrString::String(value)) => value.clone(),
+            None => "N/A".to_string(),
+        };
+        let min_available = int_or_string(spec.and_then(|spec| spec.min_available.as_ref()));
+        let max_unavailable = int_or_string(spec.and_then(|spec| spec.max_unavailable.as_ref()));
+        let allowed = self
+            .status
+            .as_ref()
+            .map(|status| status.disruptions_allowed)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        vec![
+            namespace,
+            name,
+            min_available,
+            max_unavailable,
+            allowed,
+            age,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pod_disruption_budget() {
+        let pdb = json::from_value::<policyv1::PodDisruptionBudget>(json::json!({
+            "metadata": {"name": "web", "namespace": "default"},
+            "spec": {"maxUnavailable": "50%"},
+            "status": {
+                "currentHealthy": 3,
+                "desiredHealthy": 2,
+                "disruptionsAllowed": 1,
+                "expectedPods": 3,
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            pdb.data(&default(), &OutputFormat::Normal),
+            ["default", "web", "N/A", "50%", "1", "<unknown>"]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+impl Show for corev1::ResourceQuota {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "AGE", "REQUEST", "LIMIT"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let age = object_age(self, params);
+        let status = self.status.as_ref();
+        let hard = status.and_then(|status| status.hard.as_ref());
+        let used = status.and_then(|status| status.used.as_ref());
+        let (limits, requests) = hard
+            .into_iter()
+            .flatten()
+            .map(|(resource, hard)| {
+                let used = used
+                    .and_then(|used| used.get(resource))
+                    .map_or("0", |quantity| quantity.0.as_str());
+                (resource, format!("{resource}: {used}/{}", hard.0))
+            })
+            .partition::<Vec<_>, _>(|(resource, _)| resource.starts_with("limits."));
+        let join = |quota: Vec<(&String, String)>| {
+            quota
+                .into_iter()
+                .map(|(_, text)| text)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        vec![namespace, name, age, join(requests), join(limits)]
+    }
+}
+
+impl Show for corev1::LimitRange {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "CREATED AT"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        vec![namespace, name, created_at(self)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_quota() {
+        let quota = json::from_value::<corev1::ResourceQuota>(json::json!({
+            "metadata": {"name": "compute", "namespace": "default"},
+            "status": {
+                "hard": {"requests.cpu": "1", "limits.memory": "2Gi", "pods": "10"},
+                "used": {"requests.cpu": "500m", "pods": "3"},
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            quota.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "compute",
+                "<unknown>",
+                "pods: 3/10, requests.cpu: 500m/1",
+                "limits.memory: 0/2Gi"
+            ]
+        );
+    }
+
+    #[test]
+    fn limit_range() {
+        let limits = json::from_value::<corev1::LimitRange>(json::json!({
+            "metadata": {
+                "name": "limits",
+                "namespace": "default",
+                "creationTimestamp": "2025-06-01T10:00:00Z",
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            limits.data(&default(), &OutputFormat::Normal),
+            ["default", "limits", "2025-06-01T10:00:00Z"]
+        );
+    }
+}
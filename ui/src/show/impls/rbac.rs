@@ -0,0 +1,182 @@
+use super::*;
+
+impl Show for rbacv1::Role {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "CREATED AT"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        vec![namespace, name, created_at(self)]
+    }
+}
+
+impl Show for rbacv1::ClusterRole {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAME", "CREATED AT"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        vec![name, created_at(self)]
+    }
+}
+
+impl Show for rbacv1::RoleBinding {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "ROLE",
+                "AGE",
+                "USERS",
+                "GROUPS",
+                "SERVICEACCOUNTS",
+            ]
+            .as_slice(),
+            _ => ["NAMESPACE", "NAME", "ROLE", "AGE"].as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let role = format!("{}/{}", self.role_ref.kind, self.role_ref.name);
+        let age = object_age(self, params);
+        let row = vec![namespace, name, role, age];
+        match output {
+            OutputFormat::Wide => row
+                .into_iter()
+                .chain(subjects(self.subjects.as_deref()))
+                .collect(),
+            _ => row,
+        }
+    }
+}
+
+impl Show for rbacv1::ClusterRoleBinding {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => {
+                ["NAME", "ROLE", "AGE", "USERS", "GROUPS", "SERVICEACCOUNTS"].as_slice()
+            }
+            _ => ["NAME", "ROLE", "AGE"].as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let role = format!("{}/{}", self.role_ref.kind, self.role_ref.name);
+        let age = object_age(self, params);
+        let row = vec![name, role, age];
+        match output {
+            OutputFormat::Wide => row
+                .into_iter()
+                .chain(subjects(self.subjects.as_deref()))
+                .collect(),
+            _ => row,
+        }
+    }
+}
+
+/// `USERS`, `GROUPS` and `SERVICEACCOUNTS` wide columns of the bindings
+fn subjects(subjects: Option<&[rbacv1::Subject]>) -> [String; 3] {
+    let subjects = subjects.unwrap_or_default();
+    let names = |kind: &str| {
+        subjects
+            .iter()
+            .filter(|subject| subject.kind == kind)
+            .map(|subject| match &subject.namespace {
+                Some(namespace) if kind == "ServiceAccount" => {
+                    format!("{namespace}/{}", subject.name)
+                }
+                _ => subject.name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    [names("User"), names("Group"), names("ServiceAccount")]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles() {
+        let metadata = json::json!({
+            "name": "reader",
+            "namespace": "default",
+            "creationTimestamp": "2025-06-01T10:00:00Z",
+        });
+        let role = json::from_value::<rbacv1::Role>(json::json!({"metadata": metadata})).unwrap();
+        assert_eq!(
+            role.data(&default(), &OutputFormat::Normal),
+            ["default", "reader", "2025-06-01T10:00:00Z"]
+        );
+        let role =
+            json::from_value::<rbacv1::ClusterRole>(json::json!({"metadata": metadata})).unwrap();
+        assert_eq!(
+            role.data(&default(), &OutputFormat::Normal),
+            ["reader", "2025-06-01T10:00:00Z"]
+        );
+    }
+
+    #[test]
+    fn role_binding() {
+        let binding = json::from_value::<rbacv1::RoleBinding>(json::json!({
+            "metadata": {"name": "read", "namespace": "default"},
+            "roleRef": {"apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "reader"},
+            "subjects": [
+                {"kind": "User", "name": "alice"},
+                {"kind": "User", "name": "bob"},
+                {"kind": "Group", "name": "devs"},
+                {"kind": "ServiceAccount", "name": "ci", "namespace": "tools"},
+            ],
+        }))
+        .unwrap();
+        assert_eq!(
+            binding.data(&default(), &OutputFormat::Wide),
+            [
+                "default",
+                "read",
+                "Role/reader",
+                "<unknown>",
+                "alice, bob",
+                "devs",
+                "tools/ci"
+            ]
+        );
+    }
+
+    #[test]
+    fn cluster_role_binding() {
+        let binding = json::from_value::<rbacv1::ClusterRoleBinding>(json::json!({
+            "metadata": {"name": "admin"},
+            "roleRef": {
+                "apiGroup": "rbac.authorization.k8s.io",
+                "kind": "ClusterRole",
+                "name": "cluster-admin",
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            binding.data(&default(), &OutputFormat::Normal),
+            ["admin", "ClusterRole/cluster-admin", "<unknown>"]
+        );
+        assert_eq!(
+            binding.data(&default(), &OutputFormat::Wide)[3..],
+            ["", "", ""]
+        );
+    }
+}
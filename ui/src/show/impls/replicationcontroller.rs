@@ -0,0 +1,86 @@
+use super::*;
+
+impl Show for corev1::ReplicationController {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let header = match output {
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "DESIRED",
+                "CURRENT",
+                "READY",
+                "AGE",
+                "CONTAINERS",
+                "IMAGES",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => ["NAMESPACE", "NAME", "DESIRED", "CURRENT", "READY", "AGE"].as_slice(),
+        };
+        header.iter().map(ToString::to_string).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let desired = spec.and_then(|spec| spec.replicas).unwrap_or(1).to_string();
+        let current = status
+            .map(|status| status.replicas)
+            .unwrap_or_default()
+            .to_string();
+        let ready = status
+            .and_then(|status| status.ready_replicas)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        let row = vec![namespace, name, desired, current, ready, age];
+        match output {
+            OutputFormat::Wide => {
+                let template = spec.and_then(|spec| spec.template.as_ref());
+                let [containers, images] = containers_and_images(template);
+                let selector = key_values(spec.and_then(|spec| spec.selector.as_ref()));
+                row.into_iter()
+                    .chain([containers, images, selector])
+                    .collect()
+            }
+            _ => row,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replication_controller() {
+        let controller = json::from_value::<corev1::ReplicationController>(json::json!({
+            "metadata": {"name": "web", "namespace": "default"},
+            "spec": {
+                "replicas": 3,
+                "selector": {"app": "web"},
+                "template": {
+                    "spec": {"containers": [{"name": "nginx", "image": "nginx"}]},
+                },
+            },
+            "status": {"replicas": 3, "readyReplicas": 1},
+        }))
+        .unwrap();
+        assert_eq!(
+            controller.data(&default(), &OutputFormat::Wide),
+            [
+                "default",
+                "web",
+                "3",
+                "3",
+                "1",
+                "<unknown>",
+                "nginx",
+                "nginx",
+                "app=web"
+            ]
+        );
+    }
+}
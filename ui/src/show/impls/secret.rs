@@ -0,0 +1,83 @@
+use super::*;
+
+impl Show for corev1::Secret {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "TYPE", "DATA", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let r#type = self.type_.clone().unwrap_or_else(|| "Opaque".to_string());
+        let data = self.data.as_ref().map(BTreeMap::len).unwrap_or_default()
+            + self
+                .string_data
+                .as_ref()
+                .map(BTreeMap::len)
+                .unwrap_or_default();
+        let age = object_age(self, params);
+        vec![namespace, name, r#type, data.to_string(), age]
+    }
+}
+
+impl Show for corev1::ServiceAccount {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAMESPACE", "NAME", "SECRETS", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let secrets = self.secrets.as_ref().map(Vec::len).unwrap_or_default();
+        let age = object_age(self, params);
+        vec![namespace, name, secrets.to_string(), age]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret() {
+        let secret = json::from_value::<corev1::Secret>(json::json!({
+            "metadata": {"name": "tls", "namespace": "default"},
+            "type": "kubernetes.io/tls",
+            "data": {"tls.crt": "", "tls.key": ""},
+        }))
+        .unwrap();
+        assert_eq!(
+            secret.data(&default(), &OutputFormat::Normal),
+            ["default", "tls", "kubernetes.io/tls", "2", "<unknown>"]
+        );
+
+        let secret = json::from_value::<corev1::Secret>(json::json!({
+            "metadata": {"name": "token", "namespace": "default"},
+            "stringData": {"token": "secret"},
+        }))
+        .unwrap();
+        assert_eq!(
+            secret.data(&default(), &OutputFormat::Normal),
+            ["default", "token", "Opaque", "1", "<unknown>"]
+        );
+    }
+
+    #[test]
+    fn service_account() {
+        let account = json::from_value::<corev1::ServiceAccount>(json::json!({
+            "metadata": {"name": "ci", "namespace": "tools"},
+            "secrets": [{"name": "ci-token"}],
+        }))
+        .unwrap();
+        assert_eq!(
+            account.data(&default(), &OutputFormat::Normal),
+            ["tools", "ci", "1", "<unknown>"]
+        );
+    }
+}
@@ -3,9 +3,27 @@ use super::*;
 impl Show for corev1::Service {
     fn header(&self, output: &OutputFormat) -> Vec<String> {
         let header = match output {
-            OutputFormat::Normal => ["NAMESPACE", "NAME"].as_slice(),
-            OutputFormat::Wide => ["NAMESPACE", "NAME", "AGE"].as_slice(),
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => [
+                "NAMESPACE",
+                "NAME",
+                "TYPE",
+                "CLUSTER-IP",
+                "EXTERNAL-IP",
+                "PORT(S)",
+                "AGE",
+                "SELECTOR",
+            ]
+            .as_slice(),
+            _ => [
+                "NAMESPACE",
+                "NAME",
+                "TYPE",
+                "CLUSTER-IP",
+                "EXTERNAL-IP",
+                "PORT(S)",
+                "AGE",
+            ]
+            .as_slice(),
         };
         header.iter().map(ToString::to_string).collect()
     }
@@ -13,25 +31,33 @@ impl Show for corev1::Service {
     fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
         let namespace = self.namespace().unwrap_or_default();
         let name = name(self, params);
-        let age = self.creation_timestamp().map(age).unwrap_or_default();
+        let r#type = self.kubectl_type().to_string();
+        let cluster_ip = ServiceGetExt2::spec(self)
+            .and_then(|spec| spec.cluster_ip.clone())
+            .filter(|ip| !ip.is_empty())
+            .unwrap_or_else(|| "<none>".to_string());
+        let external_ip = self.kubectl_external_ip();
+        let ports = self.kubectl_ports().unwrap_or_else(|| "<none>".to_string());
+        let age = object_age(self, params);
+        let row = vec![namespace, name, r#type, cluster_ip, external_ip, ports, age];
         match output {
-            OutputFormat::Normal => vec![namespace, name],
-            OutputFormat::Wide => vec![namespace, name, age],
-            _ => todo!("{output:?}"),
+            OutputFormat::Wide => {
+                let selector = ServiceGetExt2::spec(self)
+                    .and_then(|spec| spec.selector.as_ref())
+                    .filter(|selector| !selector.is_empty())
+                    .map_or_else(
+                        || "<none>".to_string(),
+                        |selector| {
+                            selector
+                                .iter()
+                                .map(|(key, value)| format!("{key}={value}"))
+                                .collect::<Vec<_>>()
+                                .join(",")
+                        },
+                    );
+                row.into_iter().chain([selector]).collect()
+            }
+            _ => row,
         }
     }
-
-    fn yaml(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        yaml::to_string(&data).unwrap_or_default()
-    }
-
-    fn json(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        json::to_string_pretty(&data).unwrap_or_default()
-    }
-
-    fn name(&self) -> String {
-        todo!()
-    }
 }
@@ -0,0 +1,246 @@
+use super::*;
+
+impl Show for storagev1::StorageClass {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAME",
+            "PROVISIONER",
+            "RECLAIMPOLICY",
+            "VOLUMEBINDINGMODE",
+            "ALLOWVOLUMEEXPANSION",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        const IS_DEFAULT_CLASS: &str = "storageclass.kubernetes.io/is-default-class";
+        let name = name(self, params);
+        let name = if self
+            .annotations()
+            .get(IS_DEFAULT_CLASS)
+            .is_some_and(|value| value == "true")
+        {
+            format!("{name} (default)")
+        } else {
+            name
+        };
+        let provisioner = self.provisioner.clone();
+        let reclaim_policy = self
+            .reclaim_policy
+            .clone()
+            .unwrap_or_else(|| "Delete".to_string());
+        let binding_mode = self
+            .volume_binding_mode
+            .clone()
+            .unwrap_or_else(|| "Immediate".to_string());
+        let expansion = self.allow_volume_expansion.unwrap_or_default().to_string();
+        let age = object_age(self, params);
+        vec![
+            name,
+            provisioner,
+            reclaim_policy,
+            binding_mode,
+            expansion,
+            age,
+        ]
+    }
+}
+
+impl Show for storagev1::CSIDriver {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAME",
+            "ATTACHREQUIRED",
+            "PODINFOONMOUNT",
+            "STORAGECAPACITY",
+            "TOKENREQUESTS",
+            "REQUIRESREPUBLISH",
+            "MODES",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let spec = &self.spec;
+        let attach_required = spec.attach_required.unwrap_or(true).to_string();
+        let pod_info_on_mount = spec.pod_info_on_mount.unwrap_or_default().to_string();
+        let storage_capacity = spec.storage_capacity.unwrap_or_default().to_string();
+        let token_requests = spec
+            .token_requests
+            .as_deref()
+            .filter(|requests| !requests.is_empty())
+            .map_or_else(
+                || "<unset>".to_string(),
+                |requests| {
+                    requests
+                        .iter()
+                        .map(|request| request.audience.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                },
+            );
+        let requires_republish = spec.requires_republish.unwrap_or_default().to_string();
+        let modes = spec
+            .volume_lifecycle_modes
+            .as_deref()
+            .filter(|modes| !modes.is_empty())
+            .map_or_else(|| "Persistent".to_string(), |modes| modes.join(","));
+        let age = object_age(self, params);
+        vec![
+            name,
+            attach_required,
+            pod_info_on_mount,
+            storage_capacity,
+            token_requests,
+            requires_republish,
+            modes,
+            age,
+        ]
+    }
+}
+
+impl Show for storagev1::CSINode {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAME", "DRIVERS", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let drivers = self.spec.drivers.len().to_string();
+        let age = object_age(self, params);
+        vec![name, drivers, age]
+    }
+}
+
+impl Show for storagev1::VolumeAttachment {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        ["NAME", "ATTACHER", "PV", "NODE", "ATTACHED", "AGE"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let attacher = self.spec.attacher.clone();
+        let pv = self
+            .spec
+            .source
+            .persistent_volume_name
+            .clone()
+            .unwrap_or_default();
+        let node = self.spec.node_name.clone();
+        let attached = self
+            .status
+            .as_ref()
+            .map(|status| status.attached)
+            .unwrap_or_default()
+            .to_string();
+        let age = object_age(self, params);
+        vec![name, attacher, pv, node, attached, age]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_class() {
+        let class = json::from_value::<storagev1::StorageClass>(json::json!({
+            "metadata": {
+                "name": "local-path",
+                "annotations": {"storageclass.kubernetes.io/is-default-class": "true"},
+            },
+            "provisioner": "rancher.io/local-path",
+            "volumeBindingMode": "WaitForFirstConsumer",
+        }))
+        .unwrap();
+        assert_eq!(
+            class.data(&default(), &OutputFormat::Normal),
+            [
+                "local-path (default)",
+                "rancher.io/local-path",
+                "Delete",
+                "WaitForFirstConsumer",
+                "false",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn csi_driver() {
+        let driver = json::from_value::<storagev1::CSIDriver>(json::json!({
+            "metadata": {"name": "secrets-store.csi.k8s.io"},
+            "spec": {
+                "attachRequired": false,
+                "podInfoOnMount": true,
+                "tokenRequests": [{"audience": "vault"}],
+                "volumeLifecycleModes": ["Ephemeral"],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            driver.data(&default(), &OutputFormat::Normal),
+            [
+                "secrets-store.csi.k8s.io",
+                "false",
+                "true",
+                "false",
+                "vault",
+                "false",
+                "Ephemeral",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn csi_node() {
+        let node = json::from_value::<storagev1::CSINode>(json::json!({
+            "metadata": {"name": "node-1"},
+            "spec": {"drivers": [{"name": "ebs.csi.aws.com", "nodeID": "i-0123"}]},
+        }))
+        .unwrap();
+        assert_eq!(
+            node.data(&default(), &OutputFormat::Normal),
+            ["node-1", "1", "<unknown>"]
+        );
+    }
+
+    #[test]
+    fn volume_attachment() {
+        let attachment = json::from_value::<storagev1::VolumeAttachment>(json::json!({
+            "metadata": {"name": "csi-0123"},
+            "spec": {
+                "attacher": "ebs.csi.aws.com",
+                "nodeName": "node-1",
+                "source": {"persistentVolumeName": "pv-1"},
+            },
+            "status": {"attached": true},
+        }))
+        .unwrap();
+        assert_eq!(
+            attachment.data(&default(), &OutputFormat::Normal),
+            [
+                "csi-0123",
+                "ebs.csi.aws.com",
+                "pv-1",
+                "node-1",
+                "true",
+                "<unknown>"
+            ]
+        );
+    }
+}
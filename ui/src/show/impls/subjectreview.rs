@@ -8,22 +8,9 @@ impl Show for authenticationv1::SelfSubjectReview {
             .collect()
     }
 
+    // Attribute rows are built in `normal`
     fn data(&self, _params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
-        unreachable!()
-    }
-
-    fn json(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        json::to_string_pretty(&data).unwrap_or_default()
-    }
-
-    fn yaml(&self, params: &ShowParams) -> String {
-        let data = self.maybe_strip_managed_fields(params);
-        yaml::to_string(&data).unwrap_or_default()
-    }
-
-    fn name(&self) -> String {
-        unreachable!()
+        vec![]
     }
 
     fn normal(&self, _params: &ShowParams, output: &OutputFormat) -> Table {
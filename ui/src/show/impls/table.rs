@@ -0,0 +1,231 @@
+use rkubectl_ext::TableColumnDefinition;
+use rkubectl_ext::TableRow;
+
+use super::*;
+
+impl Show for rkubectl_ext::Table {
+    fn header(&self, output: &OutputFormat) -> Vec<String> {
+        let columns = self
+            .columns(output)
+            .map(|(_, column)| column.name.to_uppercase());
+        iter::once("NAMESPACE".to_string()).chain(columns).collect()
+    }
+
+    fn data(&self, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        self.rows
+            .first()
+            .map(|row| self.row(row, params, output))
+            .unwrap_or_default()
+    }
+
+    fn normal(&self, params: &ShowParams, output: &OutputFormat) -> Table {
+        let header = label_header(self.header(output), params);
+        let data = sorted(&self.rows, params, |row| {
+            row.object.as_ref().map(Cow::Borrowed)
+        })
+        .into_iter()
+        .map(|row| label_data(self.row(row, params, output), &row.object, params));
+        iter::once(header)
+            .chain(data)
+            .collect::<tabled::builder::Builder>()
+            .build()
+    }
+
+    fn wide(&self, params: &ShowParams, output: &OutputFormat) -> Table {
+        self.normal(params, output)
+    }
+
+    fn name(&self) -> Result<String, ShowError> {
+        let kind = self.kind.as_deref().unwrap_or_default().to_lowercase();
+        let name = self
+            .column_definitions
+            .iter()
+            .position(TableColumnDefinition::is_name);
+        let names = self
+            .rows
+            .iter()
+            .filter_map(|row| row.cells.get(name?)?.as_str())
+            .map(|name| format!("{kind}/{name}"))
+            .collect::<Vec<_>>();
+        Ok(names.join("\n"))
+    }
+}
+
+trait TableExt {
+    fn columns(
+        &self,
+        output: &OutputFormat,
+    ) -> impl Iterator<Item = (usize, &TableColumnDefinition)>;
+    fn row(&self, row: &TableRow, params: &ShowParams, output: &OutputFormat) -> Vec<String>;
+}
+
+impl TableExt for rkubectl_ext::Table {
+    // Columns with non-zero priority are only shown in wide output
+    fn columns(
+        &self,
+        output: &OutputFormat,
+    ) -> impl Iterator<Item = (usize, &TableColumnDefinition)> {
+        let wide = output.is_wide();
+        self.column_definitions
+            .iter()
+            .enumerate()
+            .filter(move |(_, column)| wide || column.priority == 0)
+    }
+
+    fn row(&self, row: &TableRow, params: &ShowParams, output: &OutputFormat) -> Vec<String> {
+        let namespace = row.namespace().unwrap_or_default().to_string();
+        let cells = self.columns(output).map(|(idx, column)| {
+            let cell = row.cells.get(idx).unwrap_or(&json::Value::Null);
+            let text = cell_to_string(column, cell, params);
+            match self.kind.as_deref() {
+                Some(kind) if params.show_kind && column.is_name() => {
+                    format!("{}/{text}", kind.to_lowercase())
+                }
+                _ => text,
+            }
+        });
+        iter::once(namespace).chain(cells).collect()
+    }
+}
+
+fn cell_to_string(
+    column: &TableColumnDefinition,
+    cell: &json::Value,
+    params: &ShowParams,
+) -> String {
+    match cell {
+        json::Value::Null => "<none>".to_string(),
+        json::Value::String(text) if column.is_date() => {
+            json::from_value::<metav1::Time>(cell.clone())
+                .map_or_else(|_| text.clone(), |time| age(time, params))
+        }
+        json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> rkubectl_ext::Table {
+        let table = json::json!({
+            "kind": "Table",
+            "apiVersion": "meta.k8s.io/v1",
+            "metadata": {},
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "priority": 0},
+                {"name": "Ready", "type": "string", "format": "", "priority": 0},
+                {"name": "Selector", "type": "string", "format": "", "priority": 1},
+            ],
+            "rows": [
+                {
+                    "cells": ["nginx", "1/1", "app=nginx"],
+                    "object": {"metadata": {"name": "nginx", "namespace": "web"}},
+                },
+            ],
+        });
+        json::from_value::<rkubectl_ext::Table>(table)
+            .unwrap()
+            .with_kind("Deployment")
+    }
+
+    #[test]
+    fn priority_columns() {
+        let table = table();
+        let params = ShowParams::default();
+        assert_eq!(
+            table.header(&OutputFormat::Normal),
+            ["NAMESPACE", "NAME", "READY"]
+        );
+        assert_eq!(
+            table.header(&OutputFormat::Wide),
+            ["NAMESPACE", "NAME", "READY", "SELECTOR"]
+        );
+        assert_eq!(
+            table.data(&params, &OutputFormat::Wide),
+            ["web", "nginx", "1/1", "app=nginx"]
+        );
+    }
+
+    #[test]
+    fn show_kind() {
+        let table = table();
+        let params = ShowParams {
+            show_kind: true,
+            ..default()
+        };
+        assert_eq!(
+            table.data(&params, &OutputFormat::Normal),
+            ["web", "deployment/nginx", "1/1"]
+        );
+        assert_eq!(table.name().unwrap(), "deployment/nginx");
+    }
+
+    #[test]
+    fn sort_by_spec() {
+        let mut table = table();
+        table.rows = [("small", 1), ("large", 10), ("medium", 3)]
+            .into_iter()
+            .map(|(name, replicas)| {
+                json::from_value(json::json!({
+                    "cells": [name, "1/1", ""],
+                    "object": {"metadata": {"name": name}, "spec": {"replicas": replicas}},
+                }))
+                .unwrap()
+            })
+            .collect();
+        let params = ShowParams {
+            sort_by: JsonPath::relaxed(".spec.replicas").ok(),
+            no_headers: true,
+            ..default()
+        };
+        assert_eq!(
+            table.output(false, &params, &OutputFormat::Csv).unwrap(),
+            "small,1/1\nmedium,1/1\nlarge,1/1"
+        );
+    }
+
+    #[test]
+    fn delimited() {
+        let table = table();
+        let params = ShowParams::default();
+        assert_eq!(
+            table.output(true, &params, &OutputFormat::Csv).unwrap(),
+            "NAMESPACE,NAME,READY\nweb,nginx,1/1"
+        );
+        assert_eq!(
+            table.output(false, &params, &OutputFormat::Tsv).unwrap(),
+            "NAME\tREADY\nnginx\t1/1"
+        );
+        let params = ShowParams {
+            no_headers: true,
+            ..default()
+        };
+        assert_eq!(
+            table.output(false, &params, &OutputFormat::Wide).unwrap(),
+            "nginx   1/1   app=nginx  "
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let table = table();
+        let text = table
+            .output(false, &default(), &OutputFormat::Markdown)
+            .unwrap();
+        assert_eq!(
+            text,
+            "| NAME  | READY |\n|-------|-------|\n| nginx | 1/1   |"
+        );
+
+        let params = ShowParams {
+            no_headers: true,
+            ..default()
+        };
+        let text = table
+            .output(false, &params, &OutputFormat::Markdown)
+            .unwrap();
+        assert_eq!(text, "| nginx | 1/1 |");
+    }
+}
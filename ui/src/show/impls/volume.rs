@@ -0,0 +1,199 @@
+use super::*;
+
+impl Show for corev1::PersistentVolume {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAME",
+            "CAPACITY",
+            "ACCESS MODES",
+            "RECLAIM POLICY",
+            "STATUS",
+            "CLAIM",
+            "STORAGECLASS",
+            "VOLUMEATTRIBUTESCLASS",
+            "REASON",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let capacity = storage(spec.and_then(|spec| spec.capacity.as_ref()));
+        let access_modes = access_modes(spec.and_then(|spec| spec.access_modes.as_deref()));
+        let reclaim_policy =
+            text(spec.and_then(|spec| spec.persistent_volume_reclaim_policy.as_ref()));
+        let phase = text(status.and_then(|status| status.phase.as_ref()));
+        let claim = spec
+            .and_then(|spec| spec.claim_ref.as_ref())
+            .map(|claim| {
+                format!(
+                    "{}/{}",
+                    claim.namespace.as_deref().unwrap_or_default(),
+                    claim.name.as_deref().unwrap_or_default()
+                )
+            })
+            .unwrap_or_default();
+        let storage_class = text(spec.and_then(|spec| spec.storage_class_name.as_ref()));
+        let attributes_class =
+            attributes_class(spec.and_then(|spec| spec.volume_attributes_class_name.as_ref()));
+        let reason = text(status.and_then(|status| status.reason.as_ref()));
+        let age = object_age(self, params);
+        vec![
+            name,
+            capacity,
+            access_modes,
+            reclaim_policy,
+            phase,
+            claim,
+            storage_class,
+            attributes_class,
+            reason,
+            age,
+        ]
+    }
+}
+
+impl Show for corev1::PersistentVolumeClaim {
+    fn header(&self, _output: &OutputFormat) -> Vec<String> {
+        [
+            "NAMESPACE",
+            "NAME",
+            "STATUS",
+            "VOLUME",
+            "CAPACITY",
+            "ACCESS MODES",
+            "STORAGECLASS",
+            "VOLUMEATTRIBUTESCLASS",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn data(&self, params: &ShowParams, _output: &OutputFormat) -> Vec<String> {
+        let namespace = self.namespace().unwrap_or_default();
+        let name = name(self, params);
+        let spec = self.spec.as_ref();
+        let status = self.status.as_ref();
+        let phase = text(status.and_then(|status| status.phase.as_ref()));
+        let volume = text(spec.and_then(|spec| spec.volume_name.as_ref()));
+        let capacity = storage(status.and_then(|status| status.capacity.as_ref()));
+        let access_modes = access_modes(status.and_then(|status| status.access_modes.as_deref()));
+        let storage_class = text(spec.and_then(|spec| spec.storage_class_name.as_ref()));
+        let attributes_class =
+            attributes_class(spec.and_then(|spec| spec.volume_attributes_class_name.as_ref()));
+        let age = object_age(self, params);
+        vec![
+            namespace,
+            name,
+            phase,
+            volume,
+            capacity,
+            access_modes,
+            storage_class,
+            attributes_class,
+            age,
+        ]
+    }
+}
+
+fn text(text: Option<&String>) -> String {
+    text.cloned().unwrap_or_default()
+}
+
+fn attributes_class(class: Option<&String>) -> String {
+    class.map_or_else(|| "<unset>".to_string(), Clone::clone)
+}
+
+fn storage(capacity: Option<&BTreeMap<String, k8s::resource::Quantity>>) -> String {
+    capacity
+        .and_then(|capacity| capacity.get("storage"))
+        .map(|quantity| quantity.0.clone())
+        .unwrap_or_default()
+}
+
+/// Access modes abbreviated the way kubectl does
+fn access_modes(modes: Option<&[String]>) -> String {
+    modes
+        .unwrap_or_default()
+        .iter()
+        .map(|mode| match mode.as_str() {
+            "ReadWriteOnce" => "RWO",
+            "ReadOnlyMany" => "ROX",
+            "ReadWriteMany" => "RWX",
+            "ReadWriteOncePod" => "RWOP",
+            other => other,
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persistent_volume() {
+        let volume = json::from_value::<corev1::PersistentVolume>(json::json!({
+            "metadata": {"name": "pv-1"},
+            "spec": {
+                "capacity": {"storage": "10Gi"},
+                "accessModes": ["ReadWriteOnce"],
+                "persistentVolumeReclaimPolicy": "Retain",
+                "claimRef": {"namespace": "default", "name": "data"},
+                "storageClassName": "standard",
+            },
+            "status": {"phase": "Bound"},
+        }))
+        .unwrap();
+        assert_eq!(
+            volume.data(&default(), &OutputFormat::Normal),
+            [
+                "pv-1",
+                "10Gi",
+                "RWO",
+                "Retain",
+                "Bound",
+                "default/data",
+                "standard",
+                "<unset>",
+                "",
+                "<unknown>"
+            ]
+        );
+    }
+
+    #[test]
+    fn persistent_volume_claim() {
+        let claim = json::from_value::<corev1::PersistentVolumeClaim>(json::json!({
+            "metadata": {"name": "data", "namespace": "default"},
+            "spec": {"volumeName": "pv-1", "storageClassName": "standard"},
+            "status": {
+                "phase": "Bound",
+                "capacity": {"storage": "10Gi"},
+                "accessModes": ["ReadWriteOnce", "ReadOnlyMany"],
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            claim.data(&default(), &OutputFormat::Normal),
+            [
+                "default",
+                "data",
+                "Bound",
+                "pv-1",
+                "10Gi",
+                "RWO,ROX",
+                "standard",
+                "<unset>",
+                "<unknown>"
+            ]
+        );
+    }
+}
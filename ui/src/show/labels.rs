@@ -0,0 +1,84 @@
+use super::*;
+
+/// Header extended with a column per `-L` key and `LABELS` for `--show-labels`
+pub(crate) fn label_header(header: Vec<String>, params: &ShowParams) -> Vec<String> {
+    let columns = params.label_columns.iter().map(|key| {
+        let name = key.rsplit('/').next().unwrap_or(key);
+        name.to_uppercase()
+    });
+    let labels = params.show_labels.then(|| "LABELS".to_string());
+    header.into_iter().chain(columns).chain(labels).collect()
+}
+
+/// Row extended with the cells matching `label_header`
+pub(crate) fn label_data<T>(data: Vec<String>, object: &T, params: &ShowParams) -> Vec<String>
+where
+    T: Serialized + ?Sized,
+{
+    if params.label_columns.is_empty() && !params.show_labels {
+        return data;
+    }
+
+    // Read straight from the serde representation, whatever the type is
+    let object = object.serialized().unwrap_or_default();
+    let labels = object["metadata"]["labels"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, value)| Some((key, value.as_str()?)))
+        .collect::<BTreeMap<_, _>>();
+    let columns = params
+        .label_columns
+        .iter()
+        .map(|key| labels.get(key).copied().unwrap_or_default().to_string());
+    let all = params.show_labels.then(|| {
+        if labels.is_empty() {
+            "<none>".to_string()
+        } else {
+            labels
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join(",")
+        }
+    });
+    data.into_iter().chain(columns).chain(all).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s::PodExt;
+
+    use super::*;
+
+    #[test]
+    fn labels() {
+        let mut pod = corev1::Pod::new("foo");
+        pod.labels_mut().extend([
+            ("app".to_string(), "web".to_string()),
+            ("app.kubernetes.io/tier".to_string(), "db".to_string()),
+        ]);
+        let params = ShowParams {
+            show_labels: true,
+            label_columns: vec!["app.kubernetes.io/tier".to_string(), "zone".to_string()],
+            ..default()
+        };
+        let header = label_header(vec!["NAME".to_string()], &params);
+        assert_eq!(header, ["NAME", "TIER", "ZONE", "LABELS"]);
+        let data = label_data(vec!["foo".to_string()], &pod, &params);
+        assert_eq!(data, ["foo", "db", "", "app=web,app.kubernetes.io/tier=db"]);
+    }
+
+    #[test]
+    fn no_labels() {
+        let pod = corev1::Pod::new("foo");
+        let params = ShowParams {
+            show_labels: true,
+            ..default()
+        };
+        let data = label_data(vec!["foo".to_string()], &pod, &params);
+        assert_eq!(data, ["foo", "<none>"]);
+        let data = label_data(vec!["foo".to_string()], &pod, &default());
+        assert_eq!(data, ["foo"]);
+    }
+}
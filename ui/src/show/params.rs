@@ -1,4 +1,9 @@
-#[derive(Copy, Clone, Debug, Default, clap::Args)]
+use k8s::openapi::chrono::DateTime;
+use k8s::openapi::chrono::Utc;
+
+use super::*;
+
+#[derive(Clone, Debug, Default, clap::Args)]
 pub struct ShowParams {
     /// If present, list the resource type for the requested object(s).
     #[arg(long)]
@@ -8,7 +13,38 @@ pub struct ShowParams {
     #[arg(long)]
     pub show_labels: bool,
 
+    /// Accepts a comma separated list of labels that are going to be presented as columns.
+    /// Names are case-sensitive. You can also use multiple flag options like -L label1 -L label2...
+    #[arg(short = 'L', long, value_name = "LABEL", value_delimiter = ',')]
+    pub label_columns: Vec<String>,
+
     /// If true, keep the managedFields when printing objects in JSON or YAML format.
     #[arg(long)]
     pub show_managed_fields: bool,
+
+    /// When using the default, wide, csv, tsv or markdown output format, don't print headers.
+    #[arg(long)]
+    pub no_headers: bool,
+
+    /// If non-empty, sort list types using this field specification.
+    /// The field specification is expressed as a JSONPath expression (e.g. '{.metadata.name}').
+    #[arg(long, value_name = "JSONPATH", value_parser = JsonPath::relaxed)]
+    pub sort_by: Option<JsonPath>,
+
+    /// Reference time for ages, the current time when not set
+    #[arg(skip)]
+    pub now: Option<DateTime<Utc>>,
+}
+
+impl ShowParams {
+    pub fn with_now(self, now: DateTime<Utc>) -> Self {
+        Self {
+            now: Some(now),
+            ..self
+        }
+    }
+
+    pub(super) fn now(&self) -> DateTime<Utc> {
+        self.now.unwrap_or_else(Utc::now)
+    }
 }
@@ -0,0 +1,199 @@
+use std::cmp::Ordering;
+use std::mem;
+
+use k8s::openapi::chrono::DateTime;
+
+use super::*;
+
+/// Items in the `--sort-by` order, or in their original order without it
+pub(super) fn sorted<'a, T>(
+    items: &'a [T],
+    params: &ShowParams,
+    value: impl Fn(&T) -> Option<Cow<'_, json::Value>>,
+) -> Vec<&'a T> {
+    match order(items, params, value) {
+        Some(order) => order.into_iter().map(|idx| &items[idx]).collect(),
+        None => items.iter().collect(),
+    }
+}
+
+/// Sort the `items` of a serialized list in the `--sort-by` order
+pub(super) fn sort_items(value: &mut json::Value, params: &ShowParams) {
+    let Some(json::Value::Array(items)) = value.get_mut("items") else {
+        return;
+    };
+    if let Some(order) = order(items, params, |item| Some(Cow::Borrowed(item))) {
+        let mut unsorted = mem::take(items).into_iter().map(Some).collect::<Vec<_>>();
+        *items = order
+            .into_iter()
+            .filter_map(|idx| unsorted[idx].take())
+            .collect();
+    }
+}
+
+/// Indices of the items in the `--sort-by` order, items without the field go first
+fn order<T>(
+    items: &[T],
+    params: &ShowParams,
+    value: impl Fn(&T) -> Option<Cow<'_, json::Value>>,
+) -> Option<Vec<usize>> {
+    let sort_by = params.sort_by.as_ref()?;
+    let keys = items
+        .iter()
+        .map(|item| value(item).and_then(|value| sort_by.values(&value).into_iter().next()))
+        .collect::<Vec<_>>();
+    let compare = comparison(keys.iter().flatten());
+    let mut order = (0..items.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| match (&keys[a], &keys[b]) {
+        (Some(a), Some(b)) => compare(a, b),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    });
+    Some(order)
+}
+
+/// The comparison is decided once for all the keys, so that it is a total order.
+/// Timestamps or quantities when every key parses as such, plain text otherwise.
+fn comparison<'a>(keys: impl Iterator<Item = &'a String> + Clone) -> fn(&str, &str) -> Ordering {
+    if keys
+        .clone()
+        .all(|key| DateTime::parse_from_rfc3339(key).is_ok())
+    {
+        |a, b| {
+            DateTime::parse_from_rfc3339(a)
+                .ok()
+                .cmp(&DateTime::parse_from_rfc3339(b).ok())
+        }
+    } else if keys.into_iter().all(|key| quantity(key).is_some()) {
+        |a, b| {
+            let quantity = |text| quantity(text).unwrap_or_default();
+            quantity(a).total_cmp(&quantity(b))
+        }
+    } else {
+        str::cmp
+    }
+}
+
+/// Numeric value of a resource quantity like `100m`, `1.5Gi` or `2e3`
+fn quantity(text: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 15] = [
+        ("Ki", 1024_f64),
+        ("Mi", 1_048_576_f64),
+        ("Gi", 1_073_741_824_f64),
+        ("Ti", 1_099_511_627_776_f64),
+        ("Pi", 1_125_899_906_842_624_f64),
+        ("Ei", 1_152_921_504_606_846_976_f64),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+    let (number, multiplier) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, multiplier)| Some((text.strip_suffix(suffix)?, *multiplier)))
+        .unwrap_or((text, 1.0));
+    let number = number.parse::<f64>().ok()?;
+    number.is_finite().then_some(number * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compare(keys: &[&str]) -> fn(&str, &str) -> Ordering {
+        let keys = keys.iter().map(ToString::to_string).collect::<Vec<_>>();
+        comparison(keys.iter())
+    }
+
+    #[test]
+    fn quantities() {
+        assert_eq!(quantity("100m"), Some(0.1));
+        assert_eq!(quantity("2Ki"), Some(2048.0));
+        assert_eq!(quantity("2e3"), Some(2000.0));
+        assert_eq!(quantity("1E"), Some(1e18));
+        assert_eq!(quantity("Running"), None);
+        let keys = ["500Mi", "1Gi", "10", "9"];
+        assert_eq!(compare(&keys)("500Mi", "1Gi"), Ordering::Less);
+        assert_eq!(compare(&keys)("10", "9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn timestamps() {
+        let keys = ["2024-01-01T10:00:00Z", "2024-01-01T09:00:00-02:00"];
+        assert_eq!(compare(&keys)(keys[0], keys[1]), Ordering::Less);
+    }
+
+    #[test]
+    fn mixed_keys() {
+        // As numbers "2" < "10", as text "10" < "1a" < "2", text wins for every pair
+        let params = ShowParams {
+            sort_by: JsonPath::relaxed(".key").ok(),
+            ..default()
+        };
+        let items = ["2", "10", "1a"].map(|key| json::json!({ "key": key }));
+        let keys = sorted(&items, &params, |item| Some(Cow::Borrowed(item)))
+            .into_iter()
+            .map(|item| item["key"].as_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["10", "1a", "2"]);
+    }
+
+    #[test]
+    fn list_items() {
+        let params = ShowParams {
+            sort_by: JsonPath::relaxed(".metadata.name").ok(),
+            ..default()
+        };
+        let mut list = json::json!({"items": [
+            {"metadata": {"name": "b"}},
+            {"metadata": {"name": "a"}},
+        ]});
+        sort_items(&mut list, &params);
+        assert_eq!(list["items"][0]["metadata"]["name"], "a");
+    }
+
+    #[test]
+    fn custom_columns() {
+        let params = ShowParams {
+            sort_by: JsonPath::relaxed(".metadata.name").ok(),
+            no_headers: true,
+            ..default()
+        };
+        let namespaces = ["b", "c", "a"].map(|name| {
+            let mut namespace = corev1::Namespace::default();
+            namespace.metadata.name = Some(name.to_string());
+            namespace
+        });
+        let columns = "NAME:.metadata.name".parse().unwrap();
+        let text = namespaces
+            .to_vec()
+            .output(false, &params, &OutputFormat::CustomColumns(columns))
+            .unwrap();
+        assert_eq!(
+            text.lines().map(str::trim_end).collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn sort_by_restarts() {
+        let params = ShowParams {
+            sort_by: JsonPath::relaxed(".status.restarts").ok(),
+            ..default()
+        };
+        let items = [
+            json::json!({"name": "a", "status": {"restarts": 10}}),
+            json::json!({"name": "b"}),
+            json::json!({"name": "c", "status": {"restarts": 9}}),
+        ];
+        let names = sorted(&items, &params, |item| Some(Cow::Borrowed(item)))
+            .into_iter()
+            .map(|item| item["name"].as_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["b", "c", "a"]);
+    }
+}
@@ -0,0 +1,268 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchEventType {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl fmt::Display for WatchEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added => "ADDED".fmt(f),
+            Self::Modified => "MODIFIED".fmt(f),
+            Self::Deleted => "DELETED".fmt(f),
+        }
+    }
+}
+
+pub struct WatchEvent {
+    pub r#type: WatchEventType,
+    pub object: Box<dyn Show>,
+}
+
+impl fmt::Debug for WatchEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchEvent")
+            .field("type", &self.r#type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Prints batches of watch events as they arrive.
+/// Table output keeps the column widths seen so far, so that rows
+/// printed later stay aligned with the header printed once at the beginning.
+#[derive(Debug)]
+pub struct WatchPrinter {
+    namespace: bool,
+    output_watch_events: bool,
+    header: Option<Vec<String>>,
+    widths: Vec<usize>,
+}
+
+impl WatchPrinter {
+    const PADDING: usize = 2;
+
+    pub fn new(namespace: bool, output_watch_events: bool) -> Self {
+        // EVENT column width is known upfront
+        let widths = if output_watch_events {
+            vec![WatchEventType::Modified.to_string().len()]
+        } else {
+            vec![]
+        };
+        Self {
+            namespace,
+            output_watch_events,
+            header: None,
+            widths,
+        }
+    }
+
+    pub fn print(
+        &mut self,
+        events: &[WatchEvent],
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> Result<String, ShowError> {
+        let text = match output {
+            OutputFormat::Normal | OutputFormat::Wide => self.table(events, params, output),
+            OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Markdown => {
+                self.delimited(events, params, output)?
+            }
+            OutputFormat::Json if self.output_watch_events => events
+                .iter()
+                .map(|event| Ok(json::to_string_pretty(&self.event(event, params)?)?))
+                .collect::<Result<Vec<_>, ShowError>>()?
+                .join("\n"),
+            OutputFormat::Yaml if self.output_watch_events => events
+                .iter()
+                .map(|event| Ok(yaml::to_string(&self.event(event, params)?)?))
+                .map(|text| text.map(|text: String| format!("---\n{text}")))
+                .collect::<Result<String, ShowError>>()?,
+            OutputFormat::Yaml => events
+                .iter()
+                .map(|event| Ok(format!("---\n{}", event.object.yaml(params)?)))
+                .collect::<Result<String, ShowError>>()?,
+            _ => events
+                .iter()
+                .map(|event| event.object.output(self.namespace, params, output))
+                .collect::<Result<Vec<_>, _>>()?
+                .join("\n"),
+        };
+        Ok(text)
+    }
+
+    fn event(&self, event: &WatchEvent, params: &ShowParams) -> Result<json::Value, ShowError> {
+        let object = event.object.value(params)?;
+        Ok(json::json!({
+            "type": event.r#type.to_string(),
+            "object": object,
+        }))
+    }
+
+    fn table(
+        &mut self,
+        events: &[WatchEvent],
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> String {
+        let header = if self.header.is_none() {
+            let header = events
+                .first()
+                .map(|event| label_header(event.object.header(output), params))
+                .map(|header| self.with_event(header, "EVENT"));
+            self.header = header.clone();
+            header
+        } else {
+            None
+        };
+
+        let skip = usize::from(header.is_some() && params.no_headers);
+        let rows = header
+            .into_iter()
+            .chain(events.iter().map(|event| {
+                let data = event.object.data(params, output);
+                let data = label_data(data, &*event.object, params);
+                self.with_event(data, &event.r#type.to_string())
+            }))
+            .map(|row| self.without_namespace(row))
+            .collect::<Vec<_>>();
+
+        rows.iter().for_each(|row| self.update_widths(row));
+        rows.iter()
+            .skip(skip)
+            .map(|row| self.format_row(row))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Rows of these formats are not aligned, only the header has to be printed once
+    fn delimited(
+        &mut self,
+        events: &[WatchEvent],
+        params: &ShowParams,
+        output: &OutputFormat,
+    ) -> Result<String, ShowError> {
+        // Markdown follows the header with a separator line
+        let header_lines = match output {
+            OutputFormat::Markdown => 2,
+            _ => 1,
+        };
+        let mut lines = vec![];
+        for event in events {
+            let text = event.object.output(self.namespace, params, output)?;
+            let skip = if self.header.is_some() && !params.no_headers {
+                header_lines
+            } else {
+                0
+            };
+            self.header
+                .get_or_insert_with(|| event.object.header(output));
+            lines.extend(text.lines().skip(skip).map(str::to_string));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn with_event(&self, row: Vec<String>, event: &str) -> Vec<String> {
+        if self.output_watch_events {
+            iter::once(event.to_string()).chain(row).collect()
+        } else {
+            row
+        }
+    }
+
+    fn without_namespace(&self, mut row: Vec<String>) -> Vec<String> {
+        let column = self
+            .header
+            .as_ref()
+            .and_then(|header| header.iter().position(|column| column == "NAMESPACE"));
+        if let Some(column) = column.filter(|_| !self.namespace)
+            && column < row.len()
+        {
+            row.remove(column);
+        }
+        row
+    }
+
+    fn update_widths(&mut self, row: &[String]) {
+        if self.widths.len() < row.len() {
+            self.widths.resize(row.len(), 0);
+        }
+        self.widths
+            .iter_mut()
+            .zip(row)
+            .for_each(|(width, cell)| *width = (*width).max(cell.chars().count()));
+    }
+
+    fn format_row(&self, row: &[String]) -> String {
+        row.iter()
+            .zip(&self.widths)
+            .map(|(cell, width)| format!("{cell:<width$}", width = width + Self::PADDING))
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(r#type: WatchEventType, name: &str) -> WatchEvent {
+        let mut namespace = corev1::Namespace::default();
+        namespace.metadata.name = Some(name.to_string());
+        let object = Box::new(namespace);
+        WatchEvent { r#type, object }
+    }
+
+    #[test]
+    fn columns_stay_aligned() {
+        let params = ShowParams::default();
+        let mut printer = WatchPrinter::new(false, true);
+        let initial = printer
+            .print(
+                &[event(WatchEventType::Added, "kube-system")],
+                &params,
+                &OutputFormat::Normal,
+            )
+            .unwrap();
+        let update = printer
+            .print(
+                &[event(WatchEventType::Modified, "default")],
+                &params,
+                &OutputFormat::Normal,
+            )
+            .unwrap();
+        assert_eq!(
+            initial,
+            "EVENT     NAME         STATUS   AGE\nADDED     kube-system  Unknown  <unknown>"
+        );
+        assert_eq!(update, "MODIFIED  default      Unknown  <unknown>");
+    }
+
+    #[test]
+    fn delimited_header_once() {
+        let params = ShowParams::default();
+        let mut printer = WatchPrinter::new(false, false);
+        let initial = printer
+            .print(
+                &[event(WatchEventType::Added, "kube-system")],
+                &params,
+                &OutputFormat::Csv,
+            )
+            .unwrap();
+        let update = printer
+            .print(
+                &[
+                    event(WatchEventType::Modified, "default"),
+                    event(WatchEventType::Added, "web"),
+                ],
+                &params,
+                &OutputFormat::Csv,
+            )
+            .unwrap();
+        assert_eq!(initial, "NAME,STATUS,AGE\nkube-system,Unknown,<unknown>");
+        assert_eq!(update, "default,Unknown,<unknown>\nweb,Unknown,<unknown>");
+    }
+}